@@ -0,0 +1,267 @@
+//! A Calendar describes dates on which jobs should not run, like public holidays.
+
+use crate::{ics, Error, Result};
+use jiff::{
+	civil::{self, Weekday},
+	ToSpan as _,
+};
+use std::{collections::BTreeSet, fs, path::Path};
+
+/// How far ahead to look for a date that isn't excluded before giving up
+pub(crate) const SEARCH_DAYS: i64 = 3660;
+
+/// How many occurrences of a bounded yearly iCalendar event to expand at most
+const MAX_YEARLY_OCCURRENCES: i16 = 1000;
+
+/// A set of excluded dates, built from explicit dates, date ranges, recurring annual dates,
+/// and weekends.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn main() -> Result<()> {
+/// use jiff::civil::date;
+/// let holidays = Calendar::new()
+///     .weekends()
+///     .annual(12, 25)?
+///     .date(date(2025, 5, 26))
+///     .range(date(2025, 12, 26), date(2025, 12, 31))?;
+/// assert!(holidays.contains(date(2026, 12, 25)));
+/// assert!(!holidays.contains(date(2025, 12, 23)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calendar {
+	/// Individual excluded dates
+	dates: BTreeSet<civil::Date>,
+	/// Inclusive ranges of excluded dates
+	ranges: Vec<(civil::Date, civil::Date)>,
+	/// Month and day pairs excluded every year
+	annual: BTreeSet<(i8, i8)>,
+	/// Whether Saturdays and Sundays are excluded
+	weekends: bool,
+}
+
+impl Calendar {
+	/// Instantiate an empty Calendar, which excludes nothing
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Exclude a single date
+	#[must_use]
+	pub fn date(mut self, date: civil::Date) -> Self {
+		self.dates.insert(date);
+		self
+	}
+
+	/// Exclude every date from `first` to `last`, inclusive
+	///
+	/// # Errors
+	///
+	/// Returns an error if `last` is before `first`.
+	pub fn range(mut self, first: civil::Date, last: civil::Date) -> Result<Self> {
+		if last < first {
+			return Err(Error::InvalidDateRange);
+		}
+		self.ranges.push((first, last));
+		Ok(self)
+	}
+
+	/// Exclude the same month and day every year
+	///
+	/// # Errors
+	///
+	/// Returns an error if the month and day never occur together.
+	pub fn annual(mut self, month: i8, day: i8) -> Result<Self> {
+		// Validate against a leap year, so February 29th is allowed
+		civil::Date::new(2024, month, day)?;
+		self.annual.insert((month, day));
+		Ok(self)
+	}
+
+	/// Exclude every Saturday and Sunday
+	#[must_use]
+	pub fn weekends(mut self) -> Self {
+		self.weekends = true;
+		self
+	}
+
+	/// Exclude every all-day event in an iCalendar source, including simple yearly recurrences.
+	///
+	/// Timed events are ignored.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// let holidays = Calendar::new().ics(
+	///     "BEGIN:VCALENDAR\n\
+	///      BEGIN:VEVENT\n\
+	///      DTSTART;VALUE=DATE:20240704\n\
+	///      RRULE:FREQ=YEARLY\n\
+	///      END:VEVENT\n\
+	///      END:VCALENDAR\n",
+	/// )?;
+	/// assert!(holidays.contains(jiff::civil::date(2030, 7, 4)));
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if an event has an invalid date or an unsupported recurrence rule.
+	pub fn ics(mut self, source: &str) -> Result<Self> {
+		for event in ics::parse(source)? {
+			match &event.yearly {
+				None => self.ranges.push((event.first, event.last)),
+				Some(ics::Yearly {
+					count: None,
+					until: None,
+				}) => {
+					let mut day = event.first;
+					while day <= event.last {
+						self.annual.insert((day.month(), day.day()));
+						day = day.tomorrow()?;
+					}
+				},
+				Some(yearly) => self.add_yearly_occurrences(&event, yearly)?,
+			}
+		}
+		Ok(self)
+	}
+
+	/// Exclude every all-day event in an iCalendar file.  See `ics()`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file can't be read, or under the same conditions as `ics()`.
+	pub fn ics_file(self, path: impl AsRef<Path>) -> Result<Self> {
+		let source = fs::read_to_string(path)?;
+		self.ics(&source)
+	}
+
+	/// Expand a bounded yearly event into explicit date ranges
+	fn add_yearly_occurrences(
+		&mut self,
+		event: &ics::AllDayEvent,
+		yearly: &ics::Yearly,
+	) -> Result<()> {
+		let mut occurrences = 0;
+		for years in 0..MAX_YEARLY_OCCURRENCES {
+			if yearly.count.is_some_and(|count| occurrences >= count) {
+				break;
+			}
+			let first = event.first.checked_add(years.years())?;
+			if yearly.until.is_some_and(|until| first > until) {
+				break;
+			}
+			// Occurrences on dates that don't exist that year, like February 29th, don't count
+			if first.day() != event.first.day() {
+				continue;
+			}
+			let last = event.last.checked_add(years.years())?;
+			self.ranges.push((first, last));
+			occurrences += 1;
+		}
+		Ok(())
+	}
+
+	/// Check whether the given date is excluded
+	#[must_use]
+	pub fn contains(&self, date: civil::Date) -> bool {
+		(self.weekends && [Weekday::Saturday, Weekday::Sunday].contains(&date.weekday()))
+			|| self.dates.contains(&date)
+			|| self.annual.contains(&(date.month(), date.day()))
+			|| self
+				.ranges
+				.iter()
+				.any(|&(first, last)| first <= date && date <= last)
+	}
+
+	/// Find the first date after `date` that isn't excluded
+	pub(crate) fn next_allowed(&self, date: civil::Date) -> Result<civil::Date> {
+		let mut candidate = date;
+		for _ in 0..SEARCH_DAYS {
+			candidate = candidate.tomorrow()?;
+			if !self.contains(candidate) {
+				return Ok(candidate);
+			}
+		}
+		Err(Error::CalendarExhausted)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jiff::civil::date;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_contains() -> Result<()> {
+		let calendar = Calendar::new()
+			.date(date(2024, 7, 5))
+			.range(date(2024, 12, 24), date(2024, 12, 26))?
+			.annual(1, 1)?;
+		assert!(calendar.contains(date(2024, 7, 5)));
+		assert!(!calendar.contains(date(2025, 7, 5)));
+		assert!(calendar.contains(date(2024, 12, 24)));
+		assert!(calendar.contains(date(2024, 12, 26)));
+		assert!(!calendar.contains(date(2024, 12, 27)));
+		assert!(calendar.contains(date(2031, 1, 1)));
+		assert!(!calendar.contains(date(2024, 1, 6)));
+		assert!(calendar.clone().weekends().contains(date(2024, 1, 6)));
+		Ok(())
+	}
+
+	#[test]
+	fn test_next_allowed() -> Result<()> {
+		let calendar = Calendar::new().weekends().date(date(2024, 1, 8));
+		assert_eq!(calendar.next_allowed(date(2024, 1, 5))?, date(2024, 1, 9));
+		assert_eq!(
+			Calendar::new()
+				.range(date(2024, 1, 1), date(2099, 1, 1))?
+				.next_allowed(date(2024, 1, 1))
+				.unwrap_err()
+				.to_string(),
+			"Calendar excludes every date in the next ten years".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_ics_yearly() -> Result<()> {
+		let event = |rrule: &str| {
+			format!("BEGIN:VEVENT\nDTSTART;VALUE=DATE:20240229\nRRULE:{rrule}\nEND:VEVENT\n")
+		};
+
+		let calendar = Calendar::new().ics(&event("FREQ=YEARLY"))?;
+		assert!(calendar.contains(date(2028, 2, 29)));
+		assert!(!calendar.contains(date(2028, 2, 28)));
+
+		let calendar = Calendar::new().ics(&event("FREQ=YEARLY;COUNT=2"))?;
+		assert!(calendar.contains(date(2024, 2, 29)));
+		assert!(calendar.contains(date(2028, 2, 29)));
+		assert!(!calendar.contains(date(2032, 2, 29)));
+		assert!(!calendar.contains(date(2025, 2, 28)));
+
+		let calendar = Calendar::new().ics(&event("FREQ=YEARLY;UNTIL=20300101"))?;
+		assert!(calendar.contains(date(2028, 2, 29)));
+		assert!(!calendar.contains(date(2032, 2, 29)));
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_invalid_dates() {
+		assert_eq!(
+			Calendar::new()
+				.range(date(2024, 1, 2), date(2024, 1, 1))
+				.unwrap_err()
+				.to_string(),
+			"Date range must not end before it starts".to_string()
+		);
+		assert!(Calendar::new().annual(2, 29).is_ok());
+		assert!(Calendar::new().annual(2, 30).is_err());
+	}
+}
@@ -1,6 +1,10 @@
 //! The work functions that can be scheduled must implement the `Callable` trait.
 
-use std::fmt;
+use jiff::Span;
+use std::{
+	cell::{Cell, RefCell},
+	fmt,
+};
 
 /// A job is anything that implements this trait
 pub trait Callable {
@@ -8,6 +12,10 @@ pub trait Callable {
 	fn call(&self) -> Option<bool>;
 	/// Get the name of this callable
 	fn name(&self) -> &str;
+	/// Get the delay before the next run requested by the last call, if the callable decides
+	fn requested_delay(&self) -> Option<Span> {
+		None
+	}
 }
 
 impl fmt::Debug for dyn Callable {
@@ -397,6 +405,46 @@ where
 	}
 }
 
+/// A named callable closure returning the delay before its next run.
+pub struct Adaptive<F>
+where
+	F: FnMut() -> Span,
+{
+	name: String,
+	work: RefCell<F>,
+	delay: Cell<Option<Span>>,
+}
+
+impl<F> Adaptive<F>
+where
+	F: FnMut() -> Span,
+{
+	pub fn new(name: &str, work: F) -> Self {
+		Self {
+			name: name.into(),
+			work: RefCell::new(work),
+			delay: Cell::new(None),
+		}
+	}
+}
+
+impl<F> Callable for Adaptive<F>
+where
+	F: FnMut() -> Span,
+{
+	fn call(&self) -> Option<bool> {
+		let delay = (self.work.borrow_mut())();
+		self.delay.set(Some(delay));
+		None
+	}
+	fn name(&self) -> &str {
+		&self.name
+	}
+	fn requested_delay(&self) -> Option<Span> {
+		self.delay.get()
+	}
+}
+
 #[cfg(feature = "ffi")]
 pub mod ffi {
 	//! The CFFI feature requires different types, defined here
@@ -24,10 +24,28 @@ pub enum Error {
 	InvalidHourlyAtStr,
 	#[error("Invalid time format for minutely job (valid format is :SS)")]
 	InvalidMinuteAtStr,
-	#[error("Invalid string format for until()")]
-	InvalidUntilStr,
+	#[error("Invalid string format for until(): {0}")]
+	InvalidUntilStr(String),
 	#[error("Cannot schedule a job to run until a time in the past")]
 	InvalidUntilTime,
+	#[error("Cannot schedule a job to start after its deadline")]
+	InvalidStartTime,
+	#[error("Cannot limit a job to zero runs")]
+	InvalidTimes,
+	#[error("One-shot jobs already run exactly once, and can't be limited further")]
+	OneShotLimit,
+	#[error("Invalid time format for between(): {0}")]
+	InvalidWindowStr(String),
+	#[error("Time window must not start and end at the same time")]
+	InvalidWindow,
+	#[error("Date range must not end before it starts")]
+	InvalidDateRange,
+	#[error("Calendar excludes every date in the next ten years")]
+	CalendarExhausted,
+	#[error("Invalid iCalendar data: {0}")]
+	InvalidIcs(String),
+	#[error("{0}")]
+	Io(#[from] std::io::Error),
 	#[error("Attempted to reference the next run time but failed")]
 	NextRunUnreachable,
 	#[error("Attempted to reference the last run time but failed")]
@@ -48,6 +66,32 @@ pub enum Error {
 	WeekdayCollision(Weekday, Weekday),
 	#[error("Invalid unit without specifying start day")]
 	UnspecifiedStartDay,
+	#[error("Span between runs must be positive")]
+	InvalidSpan,
+	#[error("Invalid span string: {0}")]
+	InvalidSpanStr(String),
+	#[error("Cannot set {0}s mode, already using an explicit span")]
+	SpanUnit(Unit),
+	#[error("Alignment is only supported for whole seconds, minutes, or hours shorter than a day")]
+	AlignUnit,
+	#[error("Invalid recurrence rule: {0}")]
+	InvalidRRule(String),
+	#[error("Cannot set {0}s mode, already using a custom schedule")]
+	ScheduleUnit(Unit),
+	#[error("Invalid calendar event \"{0}\": {1}")]
+	InvalidCalendarEvent(String, String),
+	#[error("Job has no upcoming runs")]
+	NoUpcomingRuns,
+	#[error("Random times of day are only supported for daily and weekday jobs")]
+	RandomTimeUnit,
+	#[error("Jitter must be a positive span, or a percentage from 0.01 to 100")]
+	InvalidJitter,
+	#[error("Delay bounds must be positive spans of days or less, and the minimum must not exceed the maximum")]
+	InvalidDelayBounds,
+	#[error("Adaptive jobs choose their own delay, so they can't be pinned with at(), a weekday, aligned(), a custom schedule, or a single run")]
+	AdaptivePinned,
+	#[error("No jobs match {0}")]
+	NoMatchingJobs(String),
 }
 
 /// Construct a new Unit error.
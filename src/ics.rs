@@ -0,0 +1,198 @@
+//! A minimal iCalendar (RFC 5545) reader, for loading all-day holidays into a `Calendar`.
+
+use crate::{Error, Result};
+use jiff::civil;
+
+/// An all-day event read from an iCalendar source
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct AllDayEvent {
+	/// First day of the event
+	pub(crate) first: civil::Date,
+	/// Last day of the event, inclusive
+	pub(crate) last: civil::Date,
+	/// Optional yearly recurrence
+	pub(crate) yearly: Option<Yearly>,
+}
+
+/// A `FREQ=YEARLY` recurrence, optionally bounded
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Yearly {
+	/// Total number of occurrences, including the first
+	pub(crate) count: Option<u32>,
+	/// Last date an occurrence may start on
+	pub(crate) until: Option<civil::Date>,
+}
+
+/// Properties collected from a single `VEVENT`
+#[derive(Debug, Default)]
+struct RawEvent {
+	start: Option<String>,
+	end: Option<String>,
+	rrule: Option<String>,
+}
+
+/// Read every all-day `VEVENT` from an iCalendar source.  Timed events are ignored.
+pub(crate) fn parse(source: &str) -> Result<Vec<AllDayEvent>> {
+	let mut events = Vec::new();
+	let mut current: Option<RawEvent> = None;
+
+	for line in unfold(source) {
+		let Some((name, value)) = line.split_once(':') else {
+			continue;
+		};
+		// Parameters like `;VALUE=DATE` don't matter, the value's shape tells us what it is
+		let name = name
+			.split(';')
+			.next()
+			.unwrap_or_default()
+			.to_ascii_uppercase();
+		match (name.as_str(), current.as_mut()) {
+			("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+				current = Some(RawEvent::default());
+			},
+			("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+				if let Some(event) = current.take().map(all_day_event).transpose()? {
+					events.extend(event);
+				}
+			},
+			("DTSTART", Some(event)) => event.start = Some(value.to_string()),
+			("DTEND", Some(event)) => event.end = Some(value.to_string()),
+			("RRULE", Some(event)) => event.rrule = Some(value.to_string()),
+			_ => {},
+		}
+	}
+
+	Ok(events)
+}
+
+/// Join folded content lines back together
+fn unfold(source: &str) -> Vec<String> {
+	let mut lines: Vec<String> = Vec::new();
+	for line in source.lines() {
+		let line = line.trim_end_matches('\r');
+		match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+			(Some(continuation), Some(previous)) => previous.push_str(continuation),
+			_ => lines.push(line.to_string()),
+		}
+	}
+	lines
+}
+
+/// Convert the raw properties into an event, if it's an all-day event
+fn all_day_event(raw: RawEvent) -> Result<Option<AllDayEvent>> {
+	let Some(start) = raw.start else {
+		return Err(Error::InvalidIcs("VEVENT without DTSTART".to_string()));
+	};
+	if start.contains('T') {
+		return Ok(None);
+	}
+	let first = parse_date(&start)?;
+	// DTEND is exclusive for all-day events
+	let last = match raw.end {
+		Some(ref end) => parse_date(end)?.yesterday()?.max(first),
+		None => first,
+	};
+	let yearly = raw.rrule.as_deref().map(parse_yearly).transpose()?;
+	Ok(Some(AllDayEvent {
+		first,
+		last,
+		yearly,
+	}))
+}
+
+/// Parse a `YYYYMMDD` date, ignoring any time part
+fn parse_date(value: &str) -> Result<civil::Date> {
+	let invalid = || Error::InvalidIcs(format!("invalid date {value}"));
+	let digits = value.get(..8).ok_or_else(invalid)?;
+	if !digits.bytes().all(|b| b.is_ascii_digit()) {
+		return Err(invalid());
+	}
+	// Unwraps are safe, we just checked these are all digits
+	let year = digits[..4].parse().unwrap();
+	let month = digits[4..6].parse().unwrap();
+	let day = digits[6..].parse().unwrap();
+	civil::Date::new(year, month, day).map_err(|_| invalid())
+}
+
+/// Parse a simple yearly `RRULE`, rejecting anything more involved
+fn parse_yearly(rule: &str) -> Result<Yearly> {
+	let unsupported = || Error::InvalidIcs(format!("unsupported RRULE {rule}"));
+	let mut yearly = Yearly::default();
+	let mut is_yearly = false;
+	for part in rule.split(';') {
+		let (key, value) = part.split_once('=').ok_or_else(unsupported)?;
+		match key.to_ascii_uppercase().as_str() {
+			"FREQ" => is_yearly = value.eq_ignore_ascii_case("YEARLY"),
+			"INTERVAL" if value == "1" => {},
+			"COUNT" => yearly.count = Some(value.parse().map_err(|_| unsupported())?),
+			"UNTIL" => yearly.until = Some(parse_date(value)?),
+			_ => return Err(unsupported()),
+		}
+	}
+	if is_yearly {
+		Ok(yearly)
+	} else {
+		Err(unsupported())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jiff::civil::date;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_parse() -> Result<()> {
+		let source = "BEGIN:VCALENDAR\r\n\
+			BEGIN:VEVENT\r\n\
+			SUMMARY:New Year's Day\r\n\
+			DTSTART;VALUE=DATE:20240101\r\n\
+			RRULE:FREQ=YEARLY\r\n\
+			END:VEVENT\r\n\
+			BEGIN:VEVENT\r\n\
+			SUMMARY:Winter\r\n \
+			 Break\r\n\
+			DTSTART;VALUE=DATE:20241224\r\n\
+			DTEND;VALUE=DATE:\r\n 20241227\r\n\
+			END:VEVENT\r\n\
+			BEGIN:VEVENT\r\n\
+			SUMMARY:All Hands\r\n\
+			DTSTART:20240115T150000Z\r\n\
+			END:VEVENT\r\n\
+			END:VCALENDAR\r\n";
+		assert_eq!(
+			parse(source)?,
+			vec![
+				AllDayEvent {
+					first: date(2024, 1, 1),
+					last: date(2024, 1, 1),
+					yearly: Some(Yearly::default()),
+				},
+				AllDayEvent {
+					first: date(2024, 12, 24),
+					last: date(2024, 12, 26),
+					yearly: None,
+				},
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_unsupported() {
+		let event = |props: &str| format!("BEGIN:VEVENT\n{props}\nEND:VEVENT\n");
+		assert_eq!(
+			parse(&event("DTSTART;VALUE=DATE:20241301"))
+				.unwrap_err()
+				.to_string(),
+			"Invalid iCalendar data: invalid date 20241301".to_string()
+		);
+		assert_eq!(
+			parse(&event("DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=MONTHLY"))
+				.unwrap_err()
+				.to_string(),
+			"Invalid iCalendar data: unsupported RRULE FREQ=MONTHLY".to_string()
+		);
+	}
+}
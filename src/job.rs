@@ -1,13 +1,15 @@
 //! A Job is a piece of work that can be configured and added to the scheduler
 
-use jiff::{civil, Span, Zoned};
+use jiff::{civil, SignedDuration, Span, SpanRelativeTo, ToSpan as _, Zoned};
 #[cfg(feature = "random")]
 use rand::prelude::*;
 use regex::Regex;
 use std::{
+	any::Any,
 	cmp::{Ord, Ordering},
 	collections::HashSet,
 	fmt,
+	rc::Rc,
 	sync::LazyLock,
 };
 use tracing::debug;
@@ -15,9 +17,10 @@ use tracing::debug;
 #[cfg(feature = "ffi")]
 use crate::callable::ffi::ExternUnitToUnit;
 use crate::{
-	interval_error, invalid_hour_error, unit_error, weekday_collision_error, weekday_error,
-	Callable, Error, FiveToUnit, FourToUnit, OneToUnit, Result, Scheduler, SixToUnit, ThreeToUnit,
-	Timekeeper, TwoToUnit, Unit, UnitToUnit,
+	calendar::SEARCH_DAYS, interval_error, invalid_hour_error, unit_error, weekday_collision_error,
+	weekday_error, Adaptive, Calendar, Callable, Cursor, Error, FiveToUnit, FourToUnit, OnCalendar,
+	OneToUnit, RRule, Result, ResumePolicy, Schedule, Scheduler, SixToUnit, ThreeToUnit,
+	TimeWindow, Timekeeper, TwoToUnit, Unit, UnitToUnit,
 };
 
 /// A Tag is used to categorize a job.
@@ -51,24 +54,162 @@ pub fn every_single() -> Job {
 	Job::new(1)
 }
 
+/// Convenience function creating a job that runs exactly once, at the given moment.
+///
+/// E.g.: `at_datetime(deadline).run(&schedule, job)`;
+#[inline]
+#[must_use]
+pub fn at_datetime(when: Zoned) -> Job {
+	let mut job = Job::new(1);
+	job.starting_at = Some(when);
+	job.one_shot = true;
+	job.once()
+}
+
+/// Convenience function creating a job that runs exactly once, after the given delay.
+///
+/// The delay is counted from when the job is added to a scheduler.
+///
+/// E.g.: `after(45.seconds()).run(&schedule, job)`;
+#[inline]
+#[must_use]
+pub fn after(delay: Span) -> Job {
+	let mut job = Job::new(1);
+	job.start_delay = Some(delay);
+	job.one_shot = true;
+	job.once()
+}
+
+/// Controls how the next run is computed after a job executes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScheduleMode {
+	/// Wait one full period after each execution.  Runs drift by the job's own runtime plus
+	/// any loop latency.
+	#[default]
+	FixedDelay,
+	/// Anchor each run one period after the previously scheduled run, skipping any slots that
+	/// were missed entirely.  Runs never drift.
+	FixedRate,
+}
+
+/// Controls which direction jitter moves runs in.
+#[cfg(feature = "random")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JitterMode {
+	/// Only delay runs, so a job never runs before its scheduled time.
+	#[default]
+	Positive,
+	/// Move runs earlier or later by up to the jitter bound.
+	Symmetric,
+}
+
+/// Upper bound of the random offset added to each run
+#[cfg(feature = "random")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Jitter {
+	/// At most this span
+	Span(Span),
+	/// At most this many hundredths of a percent of the time between runs
+	BasisPoints(u32),
+}
+
+/// A custom schedule deciding every run of a job
+#[derive(Debug, Clone)]
+enum CustomSchedule {
+	/// A recurrence rule, owned by the job so it can be anchored when the job is added, and
+	/// where the job's last search through it left off
+	RRule(Box<RRule>, Cursor),
+	/// Any other schedule, shared with previews of the job
+	Other(Rc<dyn Schedule>),
+}
+
+impl PartialEq for CustomSchedule {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::RRule(first, first_cursor), Self::RRule(second, second_cursor)) => {
+				first == second && first_cursor == second_cursor
+			},
+			// Other schedules are opaque, so they're only equal to themselves
+			(Self::Other(first), Self::Other(second)) => Rc::ptr_eq(first, second),
+			_ => false,
+		}
+	}
+}
+
+impl Eq for CustomSchedule {}
+
+/// A deadline that depends on when the job is added to a scheduler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Deadline {
+	/// This long after the job is added
+	In(Span),
+	/// The next occurrence of this time of day after the job is added
+	TimeOfDay(civil::Time),
+}
+
+/// Controls what happens to runs that land on a date excluded by the job's [`Calendar`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayPolicy {
+	/// Drop the run and continue with the first scheduled run on an allowed date.
+	#[default]
+	Skip,
+	/// Move the run to the same time on the next allowed date.  The schedule then continues
+	/// from where the run would have been.
+	NextBusinessDay,
+}
+
 /// A Job is anything that can be scheduled to run periodically.
 ///
 /// Usually created by the `every` function.
 #[derive(Debug, PartialEq, Eq)]
+#[allow(clippy::struct_field_names, clippy::struct_excessive_bools)]
 pub struct Job {
 	/// A quantity of a given time unit
 	interval: Interval, // pause interval * unit between runs
+	/// Arbitrary span between runs, used instead of `interval` and `unit`
+	span: Option<Span>,
+	/// Custom schedule deciding every run, used instead of `interval` and `unit`
+	schedule: Option<CustomSchedule>,
 	/// Upper limit to interval for randomized job timing
 	#[cfg(feature = "random")]
 	latest: Option<Interval>,
+	/// Seeded source of randomness, used instead of the thread's generator if set
+	#[cfg(feature = "random")]
+	rng: Option<StdRng>,
+	/// Optional window in which each run happens at a freshly drawn random time of day
+	#[cfg(feature = "random")]
+	random_at: Option<TimeWindow>,
+	/// Optional bound of a random offset added to every run
+	#[cfg(feature = "random")]
+	jitter: Option<Jitter>,
+	/// Which direction jitter moves runs in
+	#[cfg(feature = "random")]
+	jitter_mode: JitterMode,
+	/// Where the next run was scheduled before jitter moved it
+	#[cfg(feature = "random")]
+	unjittered: Option<Zoned>,
+	/// Delay before the next run requested by an adaptive job, used instead of `interval`
+	adaptive_delay: Option<Span>,
+	/// Optional lower and upper limits for delays requested by an adaptive job
+	delay_bounds: Option<(SignedDuration, SignedDuration)>,
 	/// The actual function to execute
 	job: Option<Box<dyn Callable>>,
+	/// Optional stable name identifying the job
+	name: Option<String>,
 	/// Tags used to group jobs
 	tags: HashSet<Tag>,
 	/// Unit of time described by intervals
 	unit: Option<Unit>,
-	/// Optional set time at which this job runs
-	at_time: Option<civil::Time>,
+	/// Optional set times at which this job runs, in order
+	at_times: Vec<civil::Time>,
+	/// Optional time-of-day window outside of which this job never runs
+	window: Option<TimeWindow>,
+	/// Optional dates on which this job never runs
+	except: Option<Calendar>,
+	/// What to do with runs on excluded dates
+	holiday_policy: HolidayPolicy,
+	/// Where the next run would have been, if it was moved off an excluded date
+	shifted_from: Option<Zoned>,
 	/// Timestamp of last run
 	last_run: Option<Zoned>,
 	/// Timestamp of next run
@@ -77,8 +218,28 @@ pub struct Job {
 	period: Option<Span>,
 	/// Specific day of the week to start on
 	start_day: Option<civil::Weekday>,
+	/// How to anchor the next run after an execution
+	mode: ScheduleMode,
+	/// Whether runs snap to clock boundaries of the period, counting from midnight
+	aligned: bool,
+	/// Whether the first run happens as soon as the job is scheduled
+	immediately: bool,
+	/// Optional time before which the job never runs
+	starting_at: Option<Zoned>,
+	/// Optional delay before the first run, counted from when the job is added to a scheduler
+	start_delay: Option<Span>,
 	/// Optional time of final run
 	pub(crate) cancel_after: Option<Zoned>,
+	/// Optional deadline counted from when the job is added to a scheduler
+	deadline: Option<Deadline>,
+	/// Optional number of runs the job was limited to
+	run_limit: Option<u32>,
+	/// Optional number of runs left before the job cancels itself
+	remaining_runs: Option<u32>,
+	/// Whether the job runs exactly once, at `starting_at`
+	one_shot: bool,
+	/// Whether the job is skipped until it's resumed
+	paused: bool,
 	// Track number of times run, for testing
 	#[cfg(test)]
 	pub(crate) call_count: u64,
@@ -89,22 +250,230 @@ impl Job {
 	pub fn new(interval: Interval) -> Self {
 		Self {
 			interval,
+			span: None,
+			schedule: None,
 			#[cfg(feature = "random")]
 			latest: None,
+			#[cfg(feature = "random")]
+			rng: None,
+			#[cfg(feature = "random")]
+			random_at: None,
+			#[cfg(feature = "random")]
+			jitter: None,
+			#[cfg(feature = "random")]
+			jitter_mode: JitterMode::default(),
+			#[cfg(feature = "random")]
+			unjittered: None,
+			adaptive_delay: None,
+			delay_bounds: None,
 			job: None,
+			name: None,
 			tags: HashSet::new(),
 			unit: None,
-			at_time: None,
+			at_times: Vec::new(),
+			window: None,
+			except: None,
+			holiday_policy: HolidayPolicy::default(),
+			shifted_from: None,
 			last_run: None,
 			next_run: None,
 			period: None,
 			start_day: None,
+			mode: ScheduleMode::default(),
+			aligned: false,
+			immediately: false,
+			starting_at: None,
+			start_delay: None,
 			cancel_after: None,
+			deadline: None,
+			run_limit: None,
+			remaining_runs: None,
+			one_shot: false,
+			paused: false,
 			#[cfg(test)]
 			call_count: 0,
 		}
 	}
 
+	/// Create a job that runs once per arbitrary [`Span`].
+	///
+	/// Useful for periods that don't fit a single unit, like every 1 hour and 30 minutes
+	/// or every 1 week and 2 days.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::ToSpan;
+	/// Job::every_span(1.hour().minutes(30))?.run(&mut scheduler, job)?;
+	/// Job::every_span(1.week().days(2))?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the span is zero or negative.
+	pub fn every_span(span: Span) -> Result<Self> {
+		if !span.is_positive() {
+			return Err(Error::InvalidSpan);
+		}
+		let mut job = Self::new(1);
+		job.span = Some(span);
+		Ok(job)
+	}
+
+	/// Create a job that runs once per span, parsed from an ISO 8601 or friendly duration string.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// Job::every_span_str("1h30m")?.run(&mut scheduler, job)?;
+	/// Job::every_span_str("P1W2D")?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string can't be parsed, or describes a zero or negative span.
+	pub fn every_span_str(span_str: &str) -> Result<Self> {
+		let span = span_str
+			.parse::<Span>()
+			.map_err(|_| Error::InvalidSpanStr(span_str.to_string()))?;
+		Self::every_span(span)
+	}
+
+	/// Create a job that runs on the occurrences of an RFC 5545 recurrence rule.
+	///
+	/// Accepts either a bare rule, or `DTSTART`, `RRULE`, and `EXDATE` lines.  See [`RRule`]
+	/// for what's supported.  The job cancels itself once the rule runs out of occurrences, and
+	/// still honors `until()` and `times()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// Job::rrule("FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9;BYMINUTE=0;BYSECOND=0")?
+	///     .run(&mut scheduler, job)?;
+	/// Job::rrule(
+	///     "DTSTART;TZID=Europe/Paris:20240101T170000\n\
+	///      RRULE:FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20991231T000000Z",
+	/// )?
+	/// .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the rule can't be parsed or uses unsupported parts.
+	pub fn rrule(rule: &str) -> Result<Self> {
+		Ok(Self::with_schedule(rule.parse::<RRule>()?))
+	}
+
+	/// Create a job that runs whenever a systemd `OnCalendar=` expression matches.
+	///
+	/// See [`OnCalendar`] for the supported syntax.  Without a trailing time zone, the
+	/// expression is evaluated in the local time zone.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// Job::on_calendar("Mon..Fri *-*-* 09:00:00")?.run(&mut scheduler, job)?;
+	/// Job::on_calendar("*-*-01 03:30 Europe/Berlin")?.run(&mut scheduler, job)?;
+	/// Job::on_calendar("hourly")?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the expression can't be parsed.
+	pub fn on_calendar(expression: &str) -> Result<Self> {
+		Ok(Self::with_schedule(expression.parse::<OnCalendar>()?))
+	}
+
+	/// Create a job driven by any [`Schedule`], including your own.
+	///
+	/// The job cancels itself once the schedule runs out of runs, and still honors
+	/// `between()`, `except()`, `until()`, and `times()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// let rule: RRule = "FREQ=DAILY;BYHOUR=6;BYMINUTE=0;BYSECOND=0".parse()?;
+	/// Job::with_schedule(rule).run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn with_schedule(schedule: impl Schedule + 'static) -> Self {
+		let mut job = Self::new(1);
+		// Recurrence rules without a DTSTART start when the job is added, so the job keeps its own
+		job.schedule = Some(match (&schedule as &dyn Any).downcast_ref::<RRule>() {
+			Some(rule) => CustomSchedule::RRule(Box::new(rule.clone()), Cursor::default()),
+			None => CustomSchedule::Other(Rc::new(schedule)),
+		});
+		job
+	}
+
+	/// Give the job a name.
+	///
+	/// With the `random` feature, a named job draws its random intervals from a generator
+	/// seeded by a stable hash of its name.  On a scheduler created with
+	/// `Scheduler::with_rng_seed()`, a process restarting with the same jobs gets the same
+	/// timing.  Otherwise the hash is mixed with a seed picked once per process, so a fleet of
+	/// processes running the same jobs still spreads out.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(1).hours()?.named("cache-refresh").run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn named(mut self, name: &str) -> Self {
+		self.name = Some(name.to_string());
+		self
+	}
+
+	/// The job's name, if it was given one with `named()`
+	#[must_use]
+	pub fn name(&self) -> Option<&str> {
+		self.name.as_deref()
+	}
+
+	/// Draw the job's random intervals from a generator seeded with `seed`, for reproducible
+	/// timing.  Takes precedence over `named()` and `Scheduler::with_rng_seed()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(5).to(10)?.minutes()?.rng_seed(42).run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "random")]
+	#[must_use]
+	pub fn rng_seed(mut self, seed: u64) -> Self {
+		self.rng = Some(StdRng::seed_from_u64(seed));
+		self
+	}
+
 	/// Tag the job with one or more unique identifiers
 	pub fn tag(&mut self, tags: &[&str]) {
 		for &t in tags {
@@ -115,12 +484,45 @@ impl Job {
 		}
 	}
 
+	/// Tag the job with one or more unique identifiers, while building it.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10)
+	///     .minutes()?
+	///     .tagged(&["reports", "hourly"])
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn tagged(mut self, tags: &[&str]) -> Self {
+		self.tag(tags);
+		self
+	}
+
+	/// Remove one or more tags from the job
+	pub fn untag(&mut self, tags: &[&str]) {
+		for &t in tags {
+			self.tags.remove(t);
+		}
+	}
+
+	/// The job's tags, in no particular order
+	pub fn tags(&self) -> impl Iterator<Item = &str> {
+		self.tags.iter().map(String::as_str)
+	}
+
 	/// Check if the job has the given tag
 	pub(crate) fn has_tag(&self, tag: &str) -> bool {
 		self.tags.contains(tag)
 	}
 
-	/// Specify a particular concrete time to run the job.
+	/// Specify a particular concrete time to run the job.  Calling this again adds another
+	/// time, and the job runs at whichever comes up next.
 	///
 	/// * Daily jobs: `HH:MM:SS` or `HH:MM`
 	///
@@ -206,10 +608,168 @@ impl Job {
 		}
 
 		// Store timestamp and return
-		self.at_time = Some(civil::time(hour, minute, second, 0));
+		let at_time = civil::time(hour, minute, second, 0);
+		if let Err(idx) = self.at_times.binary_search(&at_time) {
+			self.at_times.insert(idx, at_time);
+		}
+		Ok(self)
+	}
+
+	/// Specify several concrete times to run the job, using the same formats as `at()`.
+	///
+	/// The job runs at whichever of the times comes up next.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single()
+	///     .day()?
+	///     .at_times(&["09:00", "13:00", "17:30"])?
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if passed any invalid or nonsensical date string.
+	pub fn at_times(self, time_strs: &[&str]) -> Result<Self> {
+		time_strs
+			.iter()
+			.try_fold(self, |job, time_str| job.at(time_str))
+	}
+
+	/// Run the job at a random time of day between `start` and `end`, formatted `HH:MM` or
+	/// `HH:MM:SS`, drawn again for every run.  Spreads load like nightly backups across hosts.
+	///
+	/// Both ends are inclusive.  If `start` is later than `end`, the window spans midnight.  Use
+	/// `rng_seed()` or `Scheduler::with_rng_seed()` to make the drawn times reproducible.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single()
+	///     .day()?
+	///     .at_random_between("01:00", "04:00")?
+	///     .named("backup")
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the job isn't daily or on a weekday, if either time can't be parsed,
+	/// or if they're equal.
+	#[cfg(feature = "random")]
+	pub fn at_random_between(mut self, start: &str, end: &str) -> Result<Self> {
+		if self.unit != Some(Unit::Day) && self.start_day.is_none() {
+			return Err(Error::RandomTimeUnit);
+		}
+		self.random_at = Some(TimeWindow::parse(start, end)?);
+		Ok(self)
+	}
+
+	/// Only run the job between two times of day, formatted `HH:MM` or `HH:MM:SS`.
+	///
+	/// Runs that would fall outside the window roll forward to the window's start.  Both ends
+	/// are inclusive.  If `start` is later than `end`, the window spans midnight.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.between("09:00", "17:00")?.run(&mut scheduler, job)?;
+	/// every_single().hour()?.between("22:00", "06:00")?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if either time can't be parsed, or if they're equal.
+	pub fn between(mut self, start: &str, end: &str) -> Result<Self> {
+		self.window = Some(TimeWindow::parse(start, end)?);
 		Ok(self)
 	}
 
+	/// Never run the job on dates excluded by the given [`Calendar`].
+	///
+	/// By default, runs on excluded dates are skipped.  See `holiday_policy()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// let holidays = Calendar::new().weekends().annual(12, 25)?;
+	/// every_single()
+	///     .day()?
+	///     .at("09:00")?
+	///     .except(holidays)
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn except(mut self, calendar: Calendar) -> Self {
+		self.except = Some(calendar);
+		self
+	}
+
+	/// Never run the job on the all-day events in an iCalendar file, like a holiday calendar
+	/// exported by HR.  Adds to any calendar already set with `except()`.
+	///
+	/// ```no_run
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single()
+	///     .day()?
+	///     .at("09:00")?
+	///     .skip_dates_from_ics("holidays.ics")?
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file can't be read or parsed.  See [`Calendar::ics`].
+	pub fn skip_dates_from_ics(mut self, path: impl AsRef<std::path::Path>) -> Result<Self> {
+		let calendar = self.except.take().unwrap_or_default().ics_file(path)?;
+		self.except = Some(calendar);
+		Ok(self)
+	}
+
+	/// Choose what happens to runs that land on a date excluded by `except()`.  Defaults to
+	/// [`HolidayPolicy::Skip`].
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single()
+	///     .month()?
+	///     .except(Calendar::new().weekends())
+	///     .holiday_policy(HolidayPolicy::NextBusinessDay)
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn holiday_policy(mut self, policy: HolidayPolicy) -> Self {
+		self.holiday_policy = policy;
+		self
+	}
+
 	/// Schedule the job to run at a randomized interval between two extremes.
 	///
 	/// ```rust
@@ -235,6 +795,248 @@ impl Job {
 		}
 	}
 
+	/// Move every run by a random offset of up to `bound`, to keep replicas of a job from
+	/// running all at once.  Works on top of any schedule, and the schedule continues from
+	/// where each run would have been without the offset.
+	///
+	/// Offsets only delay runs by default.  See `jitter_mode()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::ToSpan;
+	/// every_single()
+	///     .day()?
+	///     .at("09:00")?
+	///     .jitter(5.minutes())?
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the bound isn't positive.
+	#[cfg(feature = "random")]
+	pub fn jitter(mut self, bound: Span) -> Result<Self> {
+		if !bound.is_positive() {
+			return Err(Error::InvalidJitter);
+		}
+		self.jitter = Some(Jitter::Span(bound));
+		Ok(self)
+	}
+
+	/// Move every run by a random offset of up to `percent` of the time between runs, to a
+	/// hundredth of a percent.  See `jitter()`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10)
+	///     .minutes()?
+	///     .jitter_percent(10.0)?
+	///     .jitter_mode(JitterMode::Symmetric)
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if `percent` isn't at least 0.01 and at most 100.
+	#[cfg(feature = "random")]
+	pub fn jitter_percent(mut self, percent: f64) -> Result<Self> {
+		if !(0.01..=100.0).contains(&percent) {
+			return Err(Error::InvalidJitter);
+		}
+		// In range, so the rounded value fits
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let basis_points = (percent * 100.0).round() as u32;
+		self.jitter = Some(Jitter::BasisPoints(basis_points));
+		Ok(self)
+	}
+
+	/// Choose which direction jitter moves runs in.  Defaults to [`JitterMode::Positive`].
+	#[cfg(feature = "random")]
+	#[must_use]
+	pub fn jitter_mode(mut self, mode: JitterMode) -> Self {
+		self.jitter_mode = mode;
+		self
+	}
+
+	/// Keep the delays requested by an adaptive job between `min` and `max`.  See
+	/// `run_adaptive()`.
+	///
+	/// Bounds have a fixed length, so they can use days, counted as 24 hours, and smaller units.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::{Span, ToSpan};
+	/// every(1)
+	///     .minutes()?
+	///     .delay_bounds(30.seconds(), 1.day())?
+	///     .run_adaptive(&mut scheduler, || -> Span { 2.days() })?;
+	/// // Requests for two days wait one day instead
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if either bound isn't positive or uses units larger than days, or if
+	/// `min` is larger than `max`.
+	pub fn delay_bounds(mut self, min: Span, max: Span) -> Result<Self> {
+		let fixed = |span: Span| {
+			span.to_jiff_duration(SpanRelativeTo::days_are_24_hours())
+				.map_err(|_| Error::InvalidDelayBounds)
+		};
+		let (min, max) = (fixed(min)?, fixed(max)?);
+		if !min.is_positive() || min > max {
+			return Err(Error::InvalidDelayBounds);
+		}
+		self.delay_bounds = Some((min, max));
+		Ok(self)
+	}
+
+	/// Choose how the next run is computed after each execution.  Defaults to
+	/// [`ScheduleMode::FixedDelay`].
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10)
+	///     .seconds()?
+	///     .schedule_mode(ScheduleMode::FixedRate)
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn schedule_mode(mut self, mode: ScheduleMode) -> Self {
+		self.mode = mode;
+		self
+	}
+
+	/// Snap runs to clock boundaries of the job's period, counting from midnight in the local
+	/// time zone.  An `at()` offset shifts every boundary by the same amount.  If the job has a
+	/// start time, boundaries are counted from the start time instead.
+	///
+	/// Only supported on periods of whole seconds, minutes, or hours shorter than a day.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// // :00, :05, :10, ...
+	/// every(5).minutes()?.aligned()?.run(&mut scheduler, job)?;
+	/// // 00:00, 06:00, 12:00, 18:00
+	/// every(6).hours()?.aligned()?.run(&mut scheduler, job)?;
+	/// // 00:30, 01:30, 02:30, ...
+	/// every_single().hour()?.at("30:00")?.aligned()?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the job's period isn't whole seconds, minutes, or hours, or if it
+	/// lasts a day or longer.
+	pub fn aligned(mut self) -> Result<Self> {
+		let period = match (self.span, self.unit) {
+			(Some(span), _) => span,
+			(None, Some(unit)) => unit.duration(self.interval),
+			(None, None) => return Err(Error::AlignUnit),
+		};
+		if !is_alignable(period) {
+			return Err(Error::AlignUnit);
+		}
+		self.aligned = true;
+		Ok(self)
+	}
+
+	/// Run the job as soon as it's scheduled, then follow its normal schedule.
+	///
+	/// Ignored if the job has a start time in the future.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single()
+	///     .day()?
+	///     .at("03:00")?
+	///     .immediately()
+	///     .run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn immediately(mut self) -> Self {
+		self.immediately = true;
+		self
+	}
+
+	/// Don't run the job before the specified moment.
+	///
+	/// Interval jobs first run exactly at `start_time`.  Jobs pinned to a weekday or an `at()`
+	/// time first run at the earliest matching slot on or after `start_time`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::{civil, ToSpan, Zoned};
+	/// let campaign = Zoned::now()
+	///     .date()
+	///     .nth_weekday(1, civil::Weekday::Monday)?
+	///     .to_zoned(jiff::tz::TimeZone::system())?;
+	/// every(2).hours()?.starting_at(campaign)?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if `start_time` is after the job's `until()` deadline.
+	pub fn starting_at(mut self, start_time: Zoned) -> Result<Self> {
+		self.set_start(start_time)?;
+		self.start_delay = None;
+		Ok(self)
+	}
+
+	/// Don't run the job until the given span has passed.
+	///
+	/// The span is counted from when the job is added to a scheduler, so a start time after
+	/// the job's `until()` deadline is only reported then.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::ToSpan;
+	/// every(10).minutes()?.starting_in(1.hour()).run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn starting_in(mut self, delay: Span) -> Self {
+		self.starting_at = None;
+		self.start_delay = Some(delay);
+		self
+	}
+
 	/// Schedule job to run until the specified moment.
 	///
 	/// The job is canceled whenever the next run is calculated and it turns out the
@@ -248,24 +1050,225 @@ impl Job {
 	/// # fn job() {}
 	/// # fn main() -> Result<()> {
 	/// # let mut scheduler = Scheduler::new();
-	/// use jiff::{ToSpan, Zoned};
-	/// let deadline = Zoned::now().checked_add(10.minutes())?;
-	/// every_single().minute()?.at(":15")?.until(deadline)?.run(&mut scheduler, job)?;
+	/// use jiff::{ToSpan, Zoned};
+	/// let deadline = Zoned::now().checked_add(10.minutes())?;
+	/// every_single().minute()?.at(":15")?.until(deadline)?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the `until_time` is before the current time, or before the job's
+	/// start time, or if the job was created by `at_datetime()` or `after()`.
+	pub fn until(mut self, until_time: Zoned) -> Result<Self> {
+		self.set_deadline(until_time)?;
+		self.deadline = None;
+		Ok(self)
+	}
+
+	/// Schedule job to run until the moment described by a string.
+	///
+	/// Accepts either a civil datetime like `2025-12-31 18:00`, interpreted in the system time
+	/// zone, or a zoned datetime like `2025-12-31T18:00[America/Chicago]`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.until_str("3000-12-31 18:00")?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string can't be parsed, or under the same conditions as `until()`.
+	pub fn until_str(self, until_str: &str) -> Result<Self> {
+		let until_time = match until_str.parse::<Zoned>() {
+			Ok(zoned) => zoned,
+			Err(_) => until_str
+				.parse::<civil::DateTime>()
+				.and_then(|dt| dt.to_zoned(jiff::tz::TimeZone::system()))
+				.map_err(|_| Error::InvalidUntilStr(until_str.to_string()))?,
+		};
+		self.until(until_time)
+	}
+
+	/// Schedule job to run until the next occurrence of a time of day, like `18:30`.
+	///
+	/// Counted from when the job is added to a scheduler: if that time has already passed
+	/// today, the job runs until that time tomorrow.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.until_time("18:30")?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string can't be parsed, or if the job was created by
+	/// `at_datetime()` or `after()`.
+	pub fn until_time(self, time_str: &str) -> Result<Self> {
+		let time = time_str
+			.parse::<civil::Time>()
+			.map_err(|_| Error::InvalidUntilStr(time_str.to_string()))?;
+		self.until_relative(Deadline::TimeOfDay(time))
+	}
+
+	/// Schedule job to run for the given span, counted from when it's added to a scheduler.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::ToSpan;
+	/// every(10).minutes()?.for_duration(2.hours())?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the span is negative, or if the job was created by `at_datetime()`
+	/// or `after()`.
+	pub fn for_duration(self, span: Span) -> Result<Self> {
+		if span.is_negative() {
+			return Err(Error::InvalidUntilTime);
+		}
+		self.until_relative(Deadline::In(span))
+	}
+
+	/// Set a deadline that's resolved when the job is added to a scheduler
+	fn until_relative(mut self, deadline: Deadline) -> Result<Self> {
+		if self.one_shot {
+			return Err(Error::OneShotLimit);
+		}
+		self.cancel_after = None;
+		self.deadline = Some(deadline);
+		Ok(self)
+	}
+
+	/// Cancel the job after it has run `runs` times.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.times(3)?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if `runs` is zero, or if the job was created by `at_datetime()` or
+	/// `after()`.
+	pub fn times(mut self, runs: u32) -> Result<Self> {
+		if runs == 0 {
+			return Err(Error::InvalidTimes);
+		}
+		if self.one_shot {
+			return Err(Error::OneShotLimit);
+		}
+		self.run_limit = Some(runs);
+		self.remaining_runs = Some(runs);
+		Ok(self)
+	}
+
+	/// Cancel the job after it has run once.  Equivalent to `times(1)`.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn job() {}
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// every_single().day()?.at("04:00")?.once().run(&mut scheduler, job)?;
 	/// # Ok(())
 	/// # }
 	/// ```
+	#[must_use]
+	pub fn once(mut self) -> Self {
+		self.run_limit = Some(1);
+		self.remaining_runs = Some(1);
+		self
+	}
+
+	/// Number of runs left before the job cancels itself, if it was limited with `times()`.
+	#[must_use]
+	pub fn remaining_runs(&self) -> Option<u32> {
+		self.remaining_runs
+	}
+
+	/// Preview the job's runs from `from` onwards, without running or rescheduling it.
 	///
-	/// # Errors
+	/// Uses the same logic as the scheduler, so it respects `until()` and `times()`.  Jobs with
+	/// randomized intervals draw a fresh interval for every run previewed.  Seeded jobs draw
+	/// from a copy of their generator, so previewing the same job twice gives the same runs.
 	///
-	/// Returns an error if the `until_time` is before the current time.
-	pub fn until(mut self, until_time: Zoned) -> Result<Self> {
-		if let Some(ref last_run) = self.last_run {
-			if until_time < *last_run {
-				return Err(Error::InvalidUntilTime);
-			}
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// use jiff::{ToSpan, Zoned};
+	/// let from: Zoned = "2024-01-01T07:00[America/New_York]".parse()?;
+	/// let runs: Vec<Zoned> = every_single().day()?.at("09:00")?.upcoming(&from).take(2).collect();
+	/// assert_eq!(runs[0], from.checked_add(2.hours())?);
+	/// assert_eq!(runs[1], from.checked_add(1.day().hours(2))?);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn upcoming(&self, from: &Zoned) -> impl Iterator<Item = Zoned> + '_ {
+		// The probe keeps its generator between runs, so every run gets a fresh draw
+		let mut probe = self.timing_clone();
+		let mut next = match probe.next_run {
+			Some(ref next) if next >= from => Some(next.clone()),
+			Some(_) => probe.advance_after(from),
+			// Jobs that aren't registered yet count relative start times and deadlines from `from`
+			None => probe
+				.resolve_relative_times(from)
+				.ok()
+				.and_then(|()| probe.first_run(from)),
+		};
+		let limit = self.remaining_runs.map_or(usize::MAX, |runs| {
+			usize::try_from(runs).unwrap_or(usize::MAX)
+		});
+		std::iter::from_fn(move || {
+			let current = next.take()?;
+			next = probe.advance_after(&current);
+			Some(current)
+		})
+		.take(limit)
+	}
+
+	/// Schedule the first run of a job that hasn't been scheduled yet, as if scheduled at `now`
+	fn first_run(&mut self, now: &Zoned) -> Option<Zoned> {
+		self.schedule_next_run(now).ok()?;
+		let next = self.next_run.clone()?;
+		(!self.is_overdue(&next)).then_some(next)
+	}
+
+	/// Reschedule as if the job ran at `after`, returning the new run unless the job is done
+	fn advance_after(&mut self, after: &Zoned) -> Option<Zoned> {
+		self.immediately = false;
+		// Before the start time, the job hasn't had a chance to run yet
+		if self
+			.starting_at
+			.as_ref()
+			.map_or(true, |start| start <= after)
+		{
+			self.last_run = Some(after.clone());
 		}
-		self.cancel_after = Some(until_time);
-		Ok(self)
+		self.schedule_next_run(after).ok()?;
+		let next = self.next_run.clone()?;
+		(next > *after && !self.is_overdue(&next)).then_some(next)
 	}
 
 	/// Specify the work function that will execute when this job runs and add it to the schedule
@@ -287,26 +1290,16 @@ impl Job {
 	///
 	/// Returns an error if unable to schedule the run.
 	// FIXME this also goes on scheduler?
-	pub fn run(mut self, scheduler: &mut Scheduler, job: fn() -> ()) -> Result<()> {
-		self.job = Some(Box::new(UnitToUnit::new("job", job)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+	pub fn run(self, scheduler: &mut Scheduler, job: fn() -> ()) -> Result<()> {
+		self.register(scheduler, Box::new(UnitToUnit::new("job", job)))
 	}
 
 	#[cfg(feature = "ffi")]
 	/// # Errors
 	///
 	/// Returns an error if unable to schedule the run.
-	pub fn run_extern(
-		mut self,
-		scheduler: &mut Scheduler,
-		job: extern "C" fn() -> (),
-	) -> Result<()> {
-		self.job = Some(Box::new(ExternUnitToUnit::new("job", job)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+	pub fn run_extern(self, scheduler: &mut Scheduler, job: extern "C" fn() -> ()) -> Result<()> {
+		self.register(scheduler, Box::new(ExternUnitToUnit::new("job", job)))
 	}
 
 	/// Specify the work function with one argument that will execute when this job runs and add it to the schedule
@@ -329,19 +1322,11 @@ impl Job {
 	/// # Errors
 	///
 	/// Returns an error if unable to schedule the run.
-	pub fn run_one_arg<T>(
-		mut self,
-		scheduler: &mut Scheduler,
-		job: fn(T) -> (),
-		arg: T,
-	) -> Result<()>
+	pub fn run_one_arg<T>(self, scheduler: &mut Scheduler, job: fn(T) -> (), arg: T) -> Result<()>
 	where
 		T: 'static + Clone,
 	{
-		self.job = Some(Box::new(OneToUnit::new("job_one_arg", job, arg)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+		self.register(scheduler, Box::new(OneToUnit::new("job_one_arg", job, arg)))
 	}
 
 	// NOTE: Doesn't work, can't use a generic fn as FFI boundary interface
@@ -381,7 +1366,7 @@ impl Job {
 	///
 	/// Returns an error if unable to schedule the run.
 	pub fn run_two_args<T, U>(
-		mut self,
+		self,
 		scheduler: &mut Scheduler,
 		job: fn(T, U) -> (),
 		arg_one: T,
@@ -391,15 +1376,10 @@ impl Job {
 		T: 'static + Clone,
 		U: 'static + Clone,
 	{
-		self.job = Some(Box::new(TwoToUnit::new(
-			"job_two_args",
-			job,
-			arg_one,
-			arg_two,
-		)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+		self.register(
+			scheduler,
+			Box::new(TwoToUnit::new("job_two_args", job, arg_one, arg_two)),
+		)
 	}
 
 	/// Specify the work function with three arguments that will execute when this job runs and add it to the schedule
@@ -424,7 +1404,7 @@ impl Job {
 	///
 	/// Returns an error if unable to schedule the run.
 	pub fn run_three_args<T, U, V>(
-		mut self,
+		self,
 		scheduler: &mut Scheduler,
 		job: fn(T, U, V) -> (),
 		arg_one: T,
@@ -436,16 +1416,16 @@ impl Job {
 		U: 'static + Clone,
 		V: 'static + Clone,
 	{
-		self.job = Some(Box::new(ThreeToUnit::new(
-			"job_three_args",
-			job,
-			arg_one,
-			arg_two,
-			arg_three,
-		)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+		self.register(
+			scheduler,
+			Box::new(ThreeToUnit::new(
+				"job_three_args",
+				job,
+				arg_one,
+				arg_two,
+				arg_three,
+			)),
+		)
 	}
 
 	/// Specify the work function with four arguments that will execute when this job runs and add it to the schedule
@@ -472,7 +1452,7 @@ impl Job {
 	///
 	/// Returns an error if unable to schedule the run.
 	pub fn run_four_args<T, U, V, W>(
-		mut self,
+		self,
 		scheduler: &mut Scheduler,
 		job: fn(T, U, V, W) -> (),
 		arg_one: T,
@@ -486,17 +1466,17 @@ impl Job {
 		V: 'static + Clone,
 		W: 'static + Clone,
 	{
-		self.job = Some(Box::new(FourToUnit::new(
-			"job_four_args",
-			job,
-			arg_one,
-			arg_two,
-			arg_three,
-			arg_four,
-		)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+		self.register(
+			scheduler,
+			Box::new(FourToUnit::new(
+				"job_four_args",
+				job,
+				arg_one,
+				arg_two,
+				arg_three,
+				arg_four,
+			)),
+		)
 	}
 
 	/// Specify the work function with five arguments that will execute when this job runs and add it to the schedule
@@ -525,7 +1505,7 @@ impl Job {
 	/// Returns an error if unable to schedule the run.
 	#[allow(clippy::too_many_arguments)]
 	pub fn run_five_args<T, U, V, W, X>(
-		mut self,
+		self,
 		scheduler: &mut Scheduler,
 		job: fn(T, U, V, W, X) -> (),
 		arg_one: T,
@@ -541,18 +1521,18 @@ impl Job {
 		W: 'static + Clone,
 		X: 'static + Clone,
 	{
-		self.job = Some(Box::new(FiveToUnit::new(
-			"job_four_args",
-			job,
-			arg_one,
-			arg_two,
-			arg_three,
-			arg_four,
-			arg_five,
-		)));
-		self.schedule_next_run(&scheduler.now())?;
-		scheduler.add_job(self);
-		Ok(())
+		self.register(
+			scheduler,
+			Box::new(FiveToUnit::new(
+				"job_four_args",
+				job,
+				arg_one,
+				arg_two,
+				arg_three,
+				arg_four,
+				arg_five,
+			)),
+		)
 	}
 
 	/// Specify the work function with six arguments that will execute when this job runs and add it to the schedule
@@ -590,7 +1570,7 @@ impl Job {
 	/// Returns an error if unable to schedule the run.
 	#[allow(clippy::too_many_arguments)]
 	pub fn run_six_args<T, U, V, W, X, Y>(
-		mut self,
+		self,
 		scheduler: &mut Scheduler,
 		job: fn(T, U, V, W, X, Y) -> (),
 		arg_one: T,
@@ -608,16 +1588,84 @@ impl Job {
 		X: 'static + Clone,
 		Y: 'static + Clone,
 	{
-		self.job = Some(Box::new(SixToUnit::new(
-			"job_four_args",
-			job,
-			arg_one,
-			arg_two,
-			arg_three,
-			arg_four,
-			arg_five,
-			arg_six,
-		)));
+		self.register(
+			scheduler,
+			Box::new(SixToUnit::new(
+				"job_four_args",
+				job,
+				arg_one,
+				arg_two,
+				arg_three,
+				arg_four,
+				arg_five,
+				arg_six,
+			)),
+		)
+	}
+
+	/// Specify a work function that decides how long to wait before its next run, and add it
+	/// to the schedule.
+	///
+	/// The job's interval sets the delay before the first run.  After that, each run waits
+	/// for the span the function returns, kept within `delay_bounds()` if set.  Returning a
+	/// span that isn't positive falls back to the job's interval.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// # let mut scheduler = Scheduler::new();
+	/// use jiff::{Span, ToSpan};
+	///
+	/// let mut idle_polls = 0;
+	/// every(30)
+	///     .seconds()?
+	///     .delay_bounds(5.seconds(), 10.minutes())?
+	///     .run_adaptive(&mut scheduler, move || -> Span {
+	///         let found_work = false;
+	///         if found_work {
+	///             idle_polls = 0;
+	///         } else {
+	///             idle_polls += 1;
+	///         }
+	///         // Back off while idle
+	///         (30 * idle_polls).seconds()
+	///     })?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the job is pinned to times of day with `at()` or
+	/// `at_random_between()`, to a weekday, to clock boundaries with `aligned()`, to a custom
+	/// schedule, or to a single run, since those decide the delay instead.  Also returns an
+	/// error if unable to schedule the run.
+	pub fn run_adaptive(
+		self,
+		scheduler: &mut Scheduler,
+		job: impl FnMut() -> Span + 'static,
+	) -> Result<()> {
+		#[cfg(feature = "random")]
+		let random_at = self.random_at.is_some();
+		#[cfg(not(feature = "random"))]
+		let random_at = false;
+		let pinned = !self.at_times.is_empty() || random_at || self.start_day.is_some();
+		if pinned || self.aligned || self.schedule.is_some() || self.one_shot {
+			return Err(Error::AdaptivePinned);
+		}
+		self.register(scheduler, Box::new(Adaptive::new("job_adaptive", job)))
+	}
+
+	/// Store the work function, compute the first run, and hand the job to the scheduler
+	fn register(mut self, scheduler: &mut Scheduler, job: Box<dyn Callable>) -> Result<()> {
+		self.job = Some(job);
+		self.resolve_relative_times(&scheduler.now())?;
+		#[cfg(feature = "random")]
+		if self.rng.is_none() {
+			self.rng = scheduler
+				.rng_seed_for(self.name.as_deref())
+				.map(StdRng::seed_from_u64);
+		}
 		self.schedule_next_run(&scheduler.now())?;
 		scheduler.add_job(self);
 		Ok(())
@@ -626,7 +1674,35 @@ impl Job {
 	/// Check whether this job should be run now
 	// FIXME I think this belongs on Scheduler
 	pub(crate) fn should_run(&self, now: &Zoned) -> bool {
-		self.next_run.is_some() && now >= self.next_run.as_ref().unwrap()
+		!self.paused && self.next_run.is_some() && now >= self.next_run.as_ref().unwrap()
+	}
+
+	/// Check if the job is paused.  See `Scheduler::pause()`.
+	#[must_use]
+	pub fn is_paused(&self) -> bool {
+		self.paused
+	}
+
+	/// Skip the job until it's resumed
+	pub(crate) fn pause(&mut self) {
+		debug!("Pausing job {self}");
+		self.paused = true;
+	}
+
+	/// Stop skipping the job, handling any run missed while it was paused according to `policy`.
+	/// Returns false if the job has no runs left.
+	pub(crate) fn resume(&mut self, now: &Zoned, policy: ResumePolicy) -> Result<bool> {
+		debug!("Resuming job {self}");
+		self.paused = false;
+		let missed = self.next_run.as_ref().is_some_and(|next| next <= now);
+		if missed && policy == ResumePolicy::Skip {
+			// One-shot jobs have nothing after the run they missed
+			if self.one_shot {
+				return Ok(false);
+			}
+			self.schedule_next_run(now)?;
+		}
+		Ok(self.next_run.is_some())
 	}
 
 	/// Run this job and immediately reschedule it, returning true.  If job should cancel, return false.
@@ -635,6 +1711,9 @@ impl Job {
 	///
 	/// If this execution causes the deadline to reach, it will run once and then return false.
 	///
+	/// If this execution uses up the last of the job's `times()`, it returns false.  Once the
+	/// limit is used up, the job does not run again and keeps returning false.
+	///
 	/// # Errors
 	///
 	/// Returns an error if unable to schedule the run.
@@ -646,20 +1725,72 @@ impl Job {
 			return Ok(false);
 		}
 
+		if self.remaining_runs == Some(0) {
+			debug!("Run limit already reached, cancelling job {self}");
+			return Ok(false);
+		}
+
 		debug!("Running job {self}");
 		if self.job.is_none() {
 			debug!("No work scheduled, moving on...");
 			return Ok(true);
 		}
 		// FIXME - here's the return value capture
+		let job = self.job.as_ref().ok_or(Error::CallableUnreachable)?;
+		let _ = job.call();
+		self.adaptive_delay = job.requested_delay();
+		#[cfg(test)]
+		{
+			self.call_count += 1;
+		}
+		self.complete_run(now)
+	}
+
+	/// Call the work function right away, leaving the schedule alone.  Returns false if the
+	/// job is past its deadline, just like `execute()`.
+	pub(crate) fn call_now(&mut self, now: &Zoned) -> Result<bool> {
+		if self.is_overdue(now) {
+			debug!("Deadline already reached, cancelling job {self}");
+			return Ok(false);
+		}
+
+		debug!("Triggering job {self}");
 		let _ = self.job.as_ref().ok_or(Error::CallableUnreachable)?.call();
 		#[cfg(test)]
 		{
 			self.call_count += 1;
 		}
+		Ok(true)
+	}
+
+	/// Go through the motions of running this job without calling anything, for dry runs.
+	/// Returns false if the job would cancel, just like `execute()`.
+	pub(crate) fn simulate_run(&mut self, now: &Zoned) -> Result<bool> {
+		if self.is_overdue(now) {
+			return Ok(false);
+		}
+		self.complete_run(now)
+	}
+
+	/// Record a run at `now` and schedule the next one, returning false if the job should cancel
+	fn complete_run(&mut self, now: &Zoned) -> Result<bool> {
 		self.last_run = Some(now.clone());
+
+		if let Some(remaining) = self.remaining_runs.as_mut() {
+			*remaining -= 1;
+			if *remaining == 0 {
+				debug!("Run limit reached, cancelling job {self}");
+				return Ok(false);
+			}
+		}
+
 		self.schedule_next_run(now)?;
 
+		if self.next_run.is_none() {
+			debug!("Schedule has no runs left, cancelling job {self}");
+			return Ok(false);
+		}
+
 		if self.is_overdue(now) {
 			debug!("Execution went over deadline, cancelling job {self}",);
 			return Ok(false);
@@ -670,7 +1801,11 @@ impl Job {
 
 	/// Shared logic for setting the job to a particular unit
 	fn set_unit_mode(mut self, unit: Unit) -> Result<Self> {
-		if let Some(u) = self.unit {
+		if self.span.is_some() {
+			Err(Error::SpanUnit(unit))
+		} else if self.schedule.is_some() {
+			Err(Error::ScheduleUnit(unit))
+		} else if let Some(u) = self.unit {
 			Err(unit_error(unit, u))
 		} else {
 			self.unit = Some(unit);
@@ -678,7 +1813,7 @@ impl Job {
 		}
 	}
 
-	/// Shared logic for setting single-interval units: second(), minute(), etc.
+	/// Shared logic for setting single-interval units: `second()`, `minute()`, etc.
 	fn set_single_unit_mode(self, unit: Unit) -> Result<Self> {
 		if self.interval == 1 {
 			self.set_unit_mode(unit)
@@ -872,6 +2007,149 @@ impl Job {
 
 	/// Compute the timestamp for the next run
 	fn schedule_next_run(&mut self, now: &Zoned) -> Result<()> {
+		// Jittered runs continue the schedule from where they would have been, plus any lateness
+		#[cfg(feature = "random")]
+		let original_now;
+		#[cfg(feature = "random")]
+		let now = match (self.unjittered.take(), self.next_run.as_ref()) {
+			(Some(original), Some(jittered)) => {
+				let lateness = jittered.duration_until(now).max(SignedDuration::ZERO);
+				original_now = original.checked_add(lateness)?;
+				self.next_run = Some(original);
+				&original_now
+			},
+			_ => now,
+		};
+
+		// Runs moved off an excluded date continue the schedule from where they would have been
+		match self.shifted_from.take() {
+			Some(ref original) => {
+				self.schedule_base_run(original)?;
+				if self.next_run.as_ref().is_some_and(|next| next <= now) {
+					self.schedule_base_run(now)?;
+				}
+			},
+			None => self.schedule_base_run(now)?,
+		}
+		self.apply_window()?;
+		self.avoid_excluded_dates()?;
+		#[cfg(feature = "random")]
+		self.apply_jitter(now)?;
+		Ok(())
+	}
+
+	/// Offset the next run by a random amount, never moving it before `now`
+	#[cfg(feature = "random")]
+	fn apply_jitter(&mut self, now: &Zoned) -> Result<()> {
+		let (Some(jitter), Some(next_run)) = (self.jitter, self.next_run.clone()) else {
+			return Ok(());
+		};
+		let bound = match jitter {
+			Jitter::Span(span) => next_run.duration_until(&next_run.checked_add(span)?),
+			Jitter::BasisPoints(basis_points) => {
+				let between_runs = match self.period {
+					Some(period) => next_run.duration_until(&next_run.checked_add(period)?),
+					None => now.duration_until(&next_run),
+				};
+				between_runs.mul_f64(f64::from(basis_points) / 10_000.0)
+			},
+		}
+		.as_secs_f64()
+		.max(0.0);
+		let low = match self.jitter_mode {
+			JitterMode::Positive => 0.0,
+			JitterMode::Symmetric => -bound,
+		};
+		let offset = match self.rng.as_mut() {
+			Some(rng) => rng.gen_range(low..=bound),
+			None => thread_rng().gen_range(low..=bound),
+		};
+		let jittered = next_run.checked_add(SignedDuration::from_secs_f64(offset))?;
+		self.next_run = Some(jittered.max(now.clone()));
+		self.unjittered = Some(next_run);
+		Ok(())
+	}
+
+	/// Keep a delay requested by an adaptive job within its bounds, falling back to the regular
+	/// period if it isn't positive
+	fn bounded_delay(&self, now: &Zoned, delay: Span) -> Result<Span> {
+		let mut delay = now.duration_until(&now.checked_add(delay)?);
+		if let Some((min, max)) = self.delay_bounds {
+			delay = delay.clamp(min, max);
+		}
+		if !delay.is_positive() {
+			return match self.span {
+				Some(span) => Ok(span),
+				None => Ok(self.unit()?.duration(self.interval)),
+			};
+		}
+		Ok(Span::try_from(delay)?)
+	}
+
+	/// Roll runs outside of the time-of-day window forward to the window's start
+	fn apply_window(&mut self) -> Result<()> {
+		if let (Some(window), Some(next_run)) = (self.window, self.next_run.as_ref()) {
+			self.next_run = Some(window.roll_forward(next_run)?);
+		}
+		Ok(())
+	}
+
+	/// Move the next run off any dates excluded by the job's calendar
+	fn avoid_excluded_dates(&mut self) -> Result<()> {
+		let Some(calendar) = self.except.clone() else {
+			return Ok(());
+		};
+
+		let mut horizon = None;
+		loop {
+			let Some(next_run) = self.next_run.clone() else {
+				return Ok(());
+			};
+			if !calendar.contains(next_run.date()) {
+				return Ok(());
+			}
+			// Give up once skipping runs reaches as far ahead as `next_allowed()` looks
+			let limit = *horizon.get_or_insert(next_run.date().checked_add(SEARCH_DAYS.days())?);
+			if next_run.date() > limit {
+				return Err(Error::CalendarExhausted);
+			}
+			let allowed = calendar.next_allowed(next_run.date())?;
+			match self.holiday_policy {
+				HolidayPolicy::NextBusinessDay => {
+					self.next_run = Some(next_run.with().date(allowed).build()?);
+					self.shifted_from = Some(next_run);
+					return Ok(());
+				},
+				HolidayPolicy::Skip => {
+					// One-shot jobs have no later run to skip to
+					if self.one_shot {
+						return Err(Error::CalendarExhausted);
+					}
+					// Schedule as if the job were registered at the start of the allowed date
+					let resume = allowed.to_zoned(next_run.time_zone().clone())?;
+					let last_run = self.last_run.take();
+					let scheduled = self.schedule_base_run(&resume);
+					self.last_run = last_run;
+					scheduled?;
+					self.apply_window()?;
+				},
+			}
+		}
+	}
+
+	/// Compute the next run from the job's configuration, before applying any restrictions
+	#[allow(clippy::too_many_lines)]
+	fn schedule_base_run(&mut self, now: &Zoned) -> Result<()> {
+		// One-shot jobs have no period, they just run at their start time
+		if self.one_shot {
+			self.next_run.clone_from(&self.starting_at);
+			return Ok(());
+		}
+
+		if self.schedule.is_some() {
+			return self.schedule_custom_run(now);
+		}
+
 		// If "latest" is set, find the actual interval for this run, otherwise just used stored val
 		let interval = {
 			#[cfg(feature = "random")]
@@ -880,137 +2158,445 @@ impl Job {
 					if v < self.interval {
 						return Err(Error::InvalidInterval);
 					}
-					thread_rng().gen_range(self.interval..v)
+					match self.rng.as_mut() {
+						Some(rng) => rng.gen_range(self.interval..v),
+						None => thread_rng().gen_range(self.interval..v),
+					}
 				},
 				None => self.interval,
 			}
-			#[cfg(not(feature = "random"))]
-			self.interval
+			#[cfg(not(feature = "random"))]
+			self.interval
+		};
+
+		// Calculate period (Duration)
+		let period = match (self.adaptive_delay, self.span) {
+			(Some(delay), _) if self.last_run.is_some() => self.bounded_delay(now, delay)?,
+			(_, Some(span)) => span,
+			_ => self.unit()?.duration(interval),
+		};
+		self.period = Some(period);
+
+		// Jobs that run on registration are due right away, unless they haven't started yet
+		if self.immediately
+			&& self.next_run.is_none()
+			&& self.last_run.is_none()
+			&& self.starting_at.as_ref().map_or(true, |start| start <= now)
+		{
+			self.next_run = Some(now.clone());
+			return Ok(());
+		}
+
+		// Jobs that haven't started yet compute their first run from the start time
+		let delayed;
+		let now = match self.starting_at.as_ref() {
+			Some(start) if self.last_run.is_none() && start > now => {
+				if self.aligned || (!self.has_times_of_day() && self.start_day.is_none()) {
+					self.next_run = Some(start.clone());
+					return Ok(());
+				}
+				delayed = start.checked_sub(1.nanosecond())?;
+				&delayed
+			},
+			_ => now,
+		};
+
+		if self.aligned {
+			self.next_run = Some(self.next_aligned_run(now, period)?);
+			return Ok(());
+		}
+
+		// Fixed rate jobs anchor on the previously scheduled run, not on when it finished
+		if self.mode == ScheduleMode::FixedRate {
+			if let Some(previous) = self.next_run.take() {
+				let mut next = previous.checked_add(period)?;
+				while next <= *now {
+					next = next.checked_add(period)?;
+				}
+				self.next_run = Some(next);
+				return Ok(());
+			}
+		}
+
+		self.next_run = Some(now + period);
+
+		// Handle start day for weekly jobs
+		if let Some(w) = self.start_day {
+			// This only makes sense for weekly jobs
+			if self.unit != Some(Unit::Week) {
+				return Err(Error::StartDayError);
+			}
+
+			let weekday_num = w.to_monday_zero_offset();
+			let mut days_ahead = i64::from(weekday_num)
+				- i64::from(
+					self.next_run
+						.as_ref()
+						.ok_or(Error::NextRunUnreachable)?
+						.date()
+						.weekday()
+						.to_monday_zero_offset(),
+				);
+
+			// Check if the weekday already happened this week, advance a week if so
+			if days_ahead <= 0 {
+				days_ahead += 7;
+			}
+
+			self.next_run = Some(
+				self.next_run()?
+					.checked_add(Unit::Day.duration(u32::try_from(days_ahead).unwrap()))
+					.unwrap()
+					.checked_sub(self.period()?)
+					.unwrap(),
+			);
+		}
+
+		// Draw this run's random time of day, if any
+		#[cfg(feature = "random")]
+		let random_time = self.draw_random_time();
+		#[cfg(not(feature = "random"))]
+		let random_time: Option<civil::Time> = None;
+
+		// Handle specified at_times, picking whichever comes up first
+		if !self.at_times.is_empty() || random_time.is_some() {
+			use Unit::{Day, Hour, Minute};
+			// Validate configuration
+			if ![Some(Day), Some(Hour), Some(Minute)].contains(&self.unit)
+				&& self.start_day.is_none()
+			{
+				return Err(Error::UnspecifiedStartDay);
+			}
+
+			let base = self.next_run()?;
+			let mut candidates = Vec::with_capacity(self.at_times.len() * 2);
+			for &at_t in &self.at_times {
+				candidates.push(self.pin_to_time(now, at_t, &base)?);
+				// Later times in the period that just ran are still due
+				if self.last_run.is_some() {
+					candidates.extend(self.later_in_period(now, at_t)?);
+				}
+			}
+			#[cfg(feature = "random")]
+			if let Some(at_t) = random_time {
+				candidates.push(self.pin_random_time(now, at_t, &base)?);
+			}
+			self.next_run = candidates.into_iter().min();
+		}
+
+		Ok(())
+	}
+
+	/// Ask the job's custom schedule for the next run
+	fn schedule_custom_run(&mut self, now: &Zoned) -> Result<()> {
+		let first_run = self.next_run.is_none() && self.last_run.is_none();
+		let started = self.starting_at.as_ref().map_or(true, |start| start <= now);
+		if self.immediately && first_run && started {
+			self.next_run = Some(now.clone());
+			return Ok(());
+		}
+
+		// Search from just before the start time, so a run right at the start counts
+		let after = match self.starting_at.as_ref() {
+			Some(start) if !started => start.checked_sub(1.nanosecond())?,
+			_ => now.clone(),
+		};
+		self.next_run = match self.schedule.as_mut().ok_or(Error::NextRunUnreachable)? {
+			CustomSchedule::RRule(rule, cursor) => rule.next_from(&after, cursor),
+			CustomSchedule::Other(schedule) => schedule.next_after(&after),
+		};
+		if first_run && self.next_run.is_none() {
+			return Err(Error::NoUpcomingRuns);
+		}
+		Ok(())
+	}
+
+	/// Move the candidate run `base` to the given time of day
+	fn pin_to_time(&self, now: &Zoned, at_t: civil::Time, base: &Zoned) -> Result<Zoned> {
+		use Unit::{Day, Hour, Minute};
+
+		let second = at_t.second();
+		let hour = if self.unit == Some(Day) || self.start_day.is_some() {
+			at_t.hour()
+		} else {
+			base.hour()
+		};
+		let minute = if [Some(Day), Some(Hour)].contains(&self.unit) || self.start_day.is_some() {
+			at_t.minute()
+		} else {
+			base.minute()
+		};
+		let naive_time = civil::time(hour, minute, second, 0);
+		let mut next_run = civil::DateTime::from_parts(base.date(), naive_time)
+			.to_zoned(base.time_zone().clone())?;
+
+		// Make sure job gets run TODAY or THIS HOUR
+		// Accounting for jobs take long enough that they finish in the next period
+		let is_late = match self.last_run {
+			Some(ref last_run) => {
+				let since = next_run.since(last_run)?;
+				since.compare((self.period()?, last_run))? == Ordering::Greater
+			},
+			None => true,
+		};
+		if is_late {
+			if self.unit == Some(Day) && at_t > now.time() && self.interval == 1 {
+				next_run = next_run.checked_sub(Day.duration(1))?;
+			} else if self.unit == Some(Hour)
+				&& (at_t.minute() > now.minute()
+					|| at_t.minute() == now.minute() && at_t.second() > now.second())
+			{
+				next_run = next_run.checked_sub(Hour.duration(1))?;
+			} else if self.unit == Some(Minute) && at_t.second() > now.second() {
+				next_run = next_run.checked_sub(Minute.duration(1))?;
+			}
+		}
+
+		// Check if at_time on given day should fire today or next week
+		if self.start_day.is_some() && now.until(&next_run)?.get_days() >= 7 {
+			next_run = next_run.checked_sub(self.period()?)?;
+		}
+
+		Ok(next_run)
+	}
+
+	/// Move the candidate run `base` to a random time of day.  Once the job has run, the time
+	/// stays in `base`'s occurrence of the window, since a fresh draw may land later in the
+	/// occurrence that just ran.
+	#[cfg(feature = "random")]
+	fn pin_random_time(&self, now: &Zoned, at_t: civil::Time, base: &Zoned) -> Result<Zoned> {
+		match self.random_at {
+			Some(window) if self.last_run.is_some() => Ok(window
+				.same_occurrence(base.datetime(), at_t)?
+				.to_zoned(base.time_zone().clone())?),
+			_ => self.pin_to_time(now, at_t, base),
+		}
+	}
+
+	/// Draw a time of day from the job's random window, if it has one
+	#[cfg(feature = "random")]
+	fn draw_random_time(&mut self) -> Option<civil::Time> {
+		let window = self.random_at?;
+		Some(match self.rng.as_mut() {
+			Some(rng) => window.random_time(rng),
+			None => window.random_time(&mut thread_rng()),
+		})
+	}
+
+	/// Check whether runs happen at particular times of day
+	fn has_times_of_day(&self) -> bool {
+		#[cfg(feature = "random")]
+		if self.random_at.is_some() {
+			return true;
+		}
+		!self.at_times.is_empty()
+	}
+
+	/// Find the given time within the same day, hour, or minute as `now`, if it's still ahead
+	fn later_in_period(&self, now: &Zoned, at_t: civil::Time) -> Result<Option<Zoned>> {
+		use Unit::{Day, Hour};
+
+		let time = if self.unit == Some(Day) || self.start_day.is_some() {
+			if self.start_day.is_some_and(|w| w != now.weekday()) {
+				return Ok(None);
+			}
+			at_t
+		} else if self.unit == Some(Hour) {
+			civil::time(now.hour(), at_t.minute(), at_t.second(), 0)
+		} else {
+			civil::time(now.hour(), now.minute(), at_t.second(), 0)
 		};
+		let candidate = now.with().time(time).build()?;
+		Ok((candidate > *now).then_some(candidate))
+	}
 
-		// Calculate period (Duration)
-		let period = self.unit()?.duration(interval);
-		self.period = Some(period);
-		self.next_run = Some(now + period);
+	/// Find the first boundary of `period` strictly after `now`, counting from midnight
+	fn next_aligned_run(&self, now: &Zoned, period: Span) -> Result<Zoned> {
+		const DAY_SECONDS: i64 = 24 * 60 * 60;
 
-		// Handle start day for weekly jobs
-		if let Some(w) = self.start_day {
-			// This only makes sense for weekly jobs
-			if self.unit != Some(Unit::Week) {
-				return Err(Error::StartDayError);
-			}
+		if !is_alignable(period) {
+			return Err(Error::AlignUnit);
+		}
+		let step = SignedDuration::try_from(period)?.as_secs();
 
-			let weekday_num = w.to_monday_zero_offset();
-			let mut days_ahead = i64::from(weekday_num)
-				- i64::from(
-					self.next_run
-						.as_ref()
-						.ok_or(Error::NextRunUnreachable)?
-						.date()
-						.weekday()
-						.to_monday_zero_offset(),
-				);
+		// Boundaries count from the start time instead, if there is one
+		if let Some(ref start) = self.starting_at {
+			let count = if now < start {
+				0
+			} else {
+				start.duration_until(now).as_secs() / step + 1
+			};
+			return Ok(start.checked_add((count * step).seconds())?);
+		}
 
-			// Check if the weekday already happened this week, advance a week if so
-			if days_ahead <= 0 {
-				days_ahead += 7;
+		let offsets = if self.at_times.is_empty() {
+			vec![0]
+		} else {
+			self.at_times
+				.iter()
+				.copied()
+				.map(seconds_since_midnight)
+				.collect()
+		};
+		let elapsed = seconds_since_midnight(now.time());
+
+		let mut candidates = Vec::with_capacity(offsets.len());
+		for offset in offsets {
+			let mut date = now.date();
+			let mut next = ((elapsed - offset).div_euclid(step) + 1) * step + offset;
+			// Boundaries restart from midnight every day
+			if next >= DAY_SECONDS {
+				date = date.tomorrow()?;
+				next = offset;
 			}
+			let time = civil::Time::midnight().checked_add(next.seconds())?;
+			candidates.push(date.to_datetime(time).to_zoned(now.time_zone().clone())?);
+		}
+		candidates
+			.into_iter()
+			.min()
+			.ok_or(Error::NextRunUnreachable)
+	}
 
-			self.next_run = Some(
-				self.next_run()?
-					.checked_add(Unit::Day.duration(u32::try_from(days_ahead).unwrap()))
-					.unwrap()
-					.checked_sub(&self.period()?)
-					.unwrap(),
+	/// Copy everything that affects timing, leaving out the work function
+	fn timing_clone(&self) -> Job {
+		Job {
+			interval: self.interval,
+			span: self.span,
+			schedule: self.schedule.clone(),
+			#[cfg(feature = "random")]
+			latest: self.latest,
+			#[cfg(feature = "random")]
+			rng: self.rng.clone(),
+			#[cfg(feature = "random")]
+			random_at: self.random_at,
+			#[cfg(feature = "random")]
+			jitter: self.jitter,
+			#[cfg(feature = "random")]
+			jitter_mode: self.jitter_mode,
+			#[cfg(feature = "random")]
+			unjittered: self.unjittered.clone(),
+			adaptive_delay: self.adaptive_delay,
+			delay_bounds: self.delay_bounds,
+			job: None,
+			name: None,
+			tags: HashSet::new(),
+			unit: self.unit,
+			at_times: self.at_times.clone(),
+			window: self.window,
+			except: self.except.clone(),
+			holiday_policy: self.holiday_policy,
+			shifted_from: self.shifted_from.clone(),
+			last_run: self.last_run.clone(),
+			next_run: self.next_run.clone(),
+			period: self.period,
+			start_day: self.start_day,
+			mode: self.mode,
+			aligned: self.aligned,
+			immediately: self.immediately,
+			starting_at: self.starting_at.clone(),
+			start_delay: self.start_delay,
+			cancel_after: self.cancel_after.clone(),
+			deadline: self.deadline,
+			run_limit: self.run_limit,
+			remaining_runs: self.remaining_runs,
+			one_shot: self.one_shot,
+			paused: self.paused,
+			#[cfg(test)]
+			call_count: 0,
+		}
+	}
+
+	/// Copy of the job's timing, reset as if it were registered at `now`, for dry runs.
+	///
+	/// Unseeded randomized jobs draw their intervals from a generator seeded with `seed`.
+	#[cfg_attr(not(feature = "random"), allow(unused_variables))]
+	pub(crate) fn simulation(&self, now: &Zoned, seed: u64) -> Result<Job> {
+		let mut probe = self.timing_clone();
+		probe.last_run = None;
+		probe.next_run = None;
+		probe.shifted_from = None;
+		probe.remaining_runs = probe.run_limit;
+		probe.resolve_relative_times(now)?;
+		if let Some(CustomSchedule::RRule(_, ref mut cursor)) = probe.schedule {
+			*cursor = Cursor::default();
+		}
+		#[cfg(feature = "random")]
+		{
+			probe.unjittered = None;
+			probe.rng = Some(
+				self.rng
+					.clone()
+					.unwrap_or_else(|| StdRng::seed_from_u64(seed)),
 			);
 		}
+		match probe.schedule_next_run(now) {
+			Ok(()) | Err(Error::NoUpcomingRuns) => Ok(probe),
+			Err(err) => Err(err),
+		}
+	}
 
-		// Handle specified at_time
-		if let Some(at_t) = self.at_time {
-			use Unit::{Day, Hour, Minute};
-			// Validate configuration
-			if ![Some(Day), Some(Hour), Some(Minute)].contains(&self.unit)
-				&& self.start_day.is_none()
-			{
-				return Err(Error::UnspecifiedStartDay);
+	/// Set the time before which the job never runs
+	fn set_start(&mut self, start_time: Zoned) -> Result<()> {
+		if let Some(ref cancel_after) = self.cancel_after {
+			if start_time > *cancel_after {
+				return Err(Error::InvalidStartTime);
 			}
+		}
+		self.starting_at = Some(start_time);
+		Ok(())
+	}
 
-			// Update next_run appropriately
-			let next_run = self.next_run()?;
-			let second = at_t.second();
-			let hour = if self.unit == Some(Day) || self.start_day.is_some() {
-				at_t.hour()
-			} else {
-				next_run.hour()
-			};
-			let minute = if [Some(Day), Some(Hour)].contains(&self.unit) || self.start_day.is_some()
-			{
-				at_t.minute()
-			} else {
-				next_run.minute()
-			};
-			let naive_time = civil::time(hour, minute, second, 0);
-			let naive_date = next_run.date();
-			let tz = next_run.time_zone();
-			let local_datetime = civil::DateTime::from_parts(naive_date, naive_time)
-				.to_zoned(tz.clone())
-				.unwrap();
-			self.next_run = Some(local_datetime);
-
-			// Make sure job gets run TODAY or THIS HOUR
-			// Accounting for jobs take long enough that they finish in the next period
-			if self.last_run.is_none()
-				|| self
-					.next_run()?
-					.since(&self.last_run()?)
-					.unwrap()
-					.compare(self.period()?)
-					.unwrap() == std::cmp::Ordering::Greater
-			{
-				if self.unit == Some(Day)
-					&& self.at_time.unwrap() > now.time()
-					&& self.interval == 1
-				{
-					// FIXME all of this should be jiffier
-					self.next_run = Some(
-						self.next_run
-							.as_ref()
-							.unwrap()
-							.checked_sub(Day.duration(1))
-							.unwrap(),
-					);
-				} else if self.unit == Some(Hour)
-					&& (self.at_time.unwrap().minute() > now.minute()
-						|| self.at_time.unwrap().minute() == now.minute()
-							&& self.at_time.unwrap().second() > now.second())
-				{
-					self.next_run = Some(self.next_run()?.checked_sub(Hour.duration(1)).unwrap());
-				} else if self.unit == Some(Minute) && self.at_time.unwrap().second() > now.second()
-				{
-					self.next_run = Some(self.next_run()?.checked_sub(Minute.duration(1)).unwrap());
-				}
+	/// Set the time after which the job is canceled
+	fn set_deadline(&mut self, until_time: Zoned) -> Result<()> {
+		if self.one_shot {
+			return Err(Error::OneShotLimit);
+		}
+		if let Some(ref last_run) = self.last_run {
+			if until_time < *last_run {
+				return Err(Error::InvalidUntilTime);
 			}
 		}
-
-		// Check if at_time on given day should fire today or next week
-		if self.start_day.is_some() && self.at_time.is_some() {
-			// unwraps are safe, we already set them in this function
-			let next = self.next_run.as_ref().unwrap(); // safe, we already set it
-			if now.until(next).unwrap().get_days() >= 7 {
-				self.next_run = Some(next.checked_sub(self.period.unwrap()).unwrap());
+		if let Some(ref starting_at) = self.starting_at {
+			if until_time < *starting_at {
+				return Err(Error::InvalidStartTime);
 			}
 		}
-
+		self.cancel_after = Some(until_time);
 		Ok(())
 	}
 
-	/// Check if given time is after the `cancel_after` time
-	fn is_overdue(&self, when: &Zoned) -> bool {
-		self.cancel_after.is_some() && when > self.cancel_after.as_ref().unwrap()
+	/// Turn start delays, deadlines, and rules without a start, given relative to registration,
+	/// into moments.  Relative times are kept, so they can be resolved again for a simulation.
+	fn resolve_relative_times(&mut self, now: &Zoned) -> Result<()> {
+		if let Some(CustomSchedule::RRule(rule, _)) = self.schedule.as_mut() {
+			rule.anchor(now);
+		}
+		// Don't hold the new start against a deadline resolved for an earlier moment
+		if self.deadline.is_some() {
+			self.cancel_after = None;
+		}
+		if let Some(delay) = self.start_delay {
+			self.set_start(now.checked_add(delay)?)?;
+		}
+		let until_time = match self.deadline {
+			Some(Deadline::In(span)) => now.checked_add(span)?,
+			Some(Deadline::TimeOfDay(time)) => {
+				let until_time = now.with().time(time).build()?;
+				if until_time <= *now {
+					until_time.checked_add(1.day())?
+				} else {
+					until_time
+				}
+			},
+			None => return Ok(()),
+		};
+		self.set_deadline(until_time)
 	}
 
-	pub(crate) fn last_run(&self) -> Result<Zoned> {
-		self.last_run.clone().ok_or(Error::LastRunUnreachable)
+	/// Check if given time is after the `cancel_after` time
+	pub(crate) fn is_overdue(&self, when: &Zoned) -> bool {
+		self.cancel_after.is_some() && when > self.cancel_after.as_ref().unwrap()
 	}
 
 	pub(crate) fn next_run(&self) -> Result<Zoned> {
@@ -1026,6 +2612,51 @@ impl Job {
 	}
 }
 
+/// Hash a name the same way on every platform and Rust version (64-bit FNV-1a)
+#[cfg(feature = "random")]
+pub(crate) fn stable_hash(name: &str) -> u64 {
+	name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+		(hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+	})
+}
+
+/// Check whether a span only uses units with a fixed length on the clock
+fn is_clock_span(span: Span) -> bool {
+	span.is_positive()
+		&& span.get_years() == 0
+		&& span.get_months() == 0
+		&& span.get_weeks() == 0
+		&& span.get_days() == 0
+}
+
+/// Check whether runs can snap to boundaries of the given period, which have to fall on whole
+/// seconds and repeat within every day
+fn is_alignable(period: Span) -> bool {
+	is_clock_span(period)
+		&& SignedDuration::try_from(period).is_ok_and(|period| {
+			period.subsec_nanos() == 0
+				&& period.as_secs() > 0
+				&& period < SignedDuration::from_hours(24)
+		})
+}
+
+/// Number of whole seconds between midnight and the given time
+fn seconds_since_midnight(time: civil::Time) -> i64 {
+	i64::from(time.hour()) * 3600 + i64::from(time.minute()) * 60 + i64::from(time.second())
+}
+
+impl Schedule for Job {
+	/// Find the run that would follow if the job ran at `after`, using the same logic as the
+	/// scheduler.  Returns `None` past the job's `until()` deadline.
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		self.timing_clone().advance_after(after)
+	}
+
+	fn describe(&self) -> String {
+		self.to_string()
+	}
+}
+
 impl PartialOrd for Job {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 		Some(self.cmp(other))
@@ -1041,12 +2672,25 @@ impl Ord for Job {
 
 impl fmt::Display for Job {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		let name = if self.job.is_none() {
-			"No Job"
-		} else {
-			let j = self.job.as_ref().unwrap();
-			j.name()
-		};
+		let name = self.job.as_ref().map_or("No Job", |j| j.name());
+		if let Some(span) = self.span {
+			return write!(f, "Job(span={span}, run={name})");
+		}
+		if let Some(ref schedule) = self.schedule {
+			let schedule = match schedule {
+				CustomSchedule::RRule(rule, _) => rule.describe(),
+				CustomSchedule::Other(schedule) => schedule.describe(),
+			};
+			return write!(f, "Job(schedule={schedule}, run={name})");
+		}
+		if self.one_shot {
+			if let Some(ref when) = self.starting_at {
+				return write!(f, "Job(at={when}, run={name})");
+			}
+			if let Some(delay) = self.start_delay {
+				return write!(f, "Job(after={delay}, run={name})");
+			}
+		}
 		let interval = self.interval;
 		let unit = self.unit;
 		write!(f, "Job(interval={interval}, unit={unit:?}, run={name})")
@@ -1293,6 +2937,260 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_every_span() -> Result<()> {
+		use jiff::ToSpan as _;
+		assert_eq!(
+			Job::every_span_str("1h30m")?.span.unwrap().fieldwise(),
+			1.hour().minutes(30).fieldwise()
+		);
+		assert_eq!(
+			Job::every_span_str("P1W2D")?.span.unwrap().fieldwise(),
+			1.week().days(2).fieldwise()
+		);
+		assert_eq!(
+			Job::every_span_str("soon").unwrap_err().to_string(),
+			"Invalid span string: soon".to_string()
+		);
+		assert_eq!(
+			Job::every_span(0.seconds()).unwrap_err().to_string(),
+			"Span between runs must be positive".to_string()
+		);
+		assert_eq!(
+			Job::every_span(90.minutes())?
+				.minutes()
+				.unwrap_err()
+				.to_string(),
+			"Cannot set minutes mode, already using an explicit span".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_aligned_calendar_units() -> Result<()> {
+		let expected =
+			"Alignment is only supported for whole seconds, minutes, or hours shorter than a day"
+				.to_string();
+		assert_eq!(
+			every(2).days()?.aligned().unwrap_err().to_string(),
+			expected
+		);
+		assert_eq!(
+			every_single().monday()?.aligned().unwrap_err().to_string(),
+			expected
+		);
+		assert_eq!(
+			Job::every_span_str("1d")?
+				.aligned()
+				.unwrap_err()
+				.to_string(),
+			expected
+		);
+		assert!(Job::every_span_str("1h30m")?.aligned().is_ok());
+
+		// Boundaries have to fall on whole seconds and repeat every day
+		for job in [
+			Job::every_span(500.milliseconds())?,
+			Job::every_span(1.second().milliseconds(500))?,
+			every(24).hours()?,
+			every(36).hours()?,
+		] {
+			assert!(matches!(job.aligned(), Err(Error::AlignUnit)));
+		}
+		assert!(every(23).hours()?.aligned().is_ok());
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_start_after_deadline() -> Result<()> {
+		let start: Zoned = "2024-01-08T09:00:00[America/New_York]".parse()?;
+		let deadline: Zoned = "2024-01-05T09:00:00[America/New_York]".parse()?;
+		let expected = "Cannot schedule a job to start after its deadline".to_string();
+		assert_eq!(
+			every_single()
+				.day()?
+				.until(deadline.clone())?
+				.starting_at(start.clone())
+				.unwrap_err()
+				.to_string(),
+			expected
+		);
+		assert_eq!(
+			every_single()
+				.day()?
+				.starting_at(start)?
+				.until(deadline)
+				.unwrap_err()
+				.to_string(),
+			expected
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_zero_times() -> Result<()> {
+		assert_eq!(
+			every_single().day()?.times(0).unwrap_err().to_string(),
+			"Cannot limit a job to zero runs".to_string()
+		);
+		assert_eq!(every_single().day()?.times(3)?.remaining_runs(), Some(3));
+		assert_eq!(every_single().day()?.once().remaining_runs(), Some(1));
+		Ok(())
+	}
+
+	#[test]
+	fn test_display_schedule() -> Result<()> {
+		assert_eq!(
+			Job::rrule("FREQ=WEEKLY;BYDAY=MO,WE")?.to_string(),
+			"Job(schedule=FREQ=WEEKLY;BYDAY=MO,WE, run=No Job)".to_string()
+		);
+		assert_eq!(
+			Job::with_schedule(crate::Window::new("02:00", "03:00")?).to_string(),
+			"Job(schedule=02:00:00..03:00:00, run=No Job)".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_limit_one_shot() -> Result<()> {
+		let when = "3000-01-01T12:00[America/Chicago]".parse::<Zoned>()?;
+		let expected = "One-shot jobs already run exactly once, and can't be limited further";
+		assert_eq!(
+			at_datetime(when.clone()).times(3).unwrap_err().to_string(),
+			expected.to_string()
+		);
+		assert_eq!(
+			at_datetime(when.clone())
+				.until(when.checked_add(1.day())?)
+				.unwrap_err()
+				.to_string(),
+			expected.to_string()
+		);
+		assert_eq!(at_datetime(when).once().remaining_runs(), Some(1));
+		Ok(())
+	}
+
+	#[test]
+	fn test_until_variants() -> Result<()> {
+		let job = every_single()
+			.day()?
+			.until_str("3000-01-01T12:00[America/Chicago]")?;
+		assert_eq!(
+			job.cancel_after.unwrap(),
+			"3000-01-01T12:00[America/Chicago]".parse::<Zoned>()?
+		);
+
+		let job = every_single().day()?.until_str("3000-01-01 12:00")?;
+		assert_eq!(
+			job.cancel_after.unwrap().datetime(),
+			civil::date(3000, 1, 1).at(12, 0, 0, 0)
+		);
+
+		let mut job = every_single().day()?.until_time("18:30")?;
+		assert_eq!(job.cancel_after, None);
+		let now = "2024-01-01T19:00[America/New_York]".parse::<Zoned>()?;
+		job.resolve_relative_times(&now)?;
+		assert_eq!(
+			job.cancel_after.unwrap(),
+			"2024-01-02T18:30[America/New_York]".parse::<Zoned>()?
+		);
+
+		assert_eq!(
+			every_single()
+				.day()?
+				.until_str("New Year's")
+				.unwrap_err()
+				.to_string(),
+			"Invalid string format for until(): New Year's".to_string()
+		);
+		assert_eq!(
+			every_single()
+				.day()?
+				.until_time("25:00")
+				.unwrap_err()
+				.to_string(),
+			"Invalid string format for until(): 25:00".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_at_is_additive() -> Result<()> {
+		let job = every_single()
+			.day()?
+			.at("17:30")?
+			.at_times(&["09:00", "13:00", "09:00"])?;
+		assert_eq!(
+			job.at_times,
+			vec![
+				civil::time(9, 0, 0, 0),
+				civil::time(13, 0, 0, 0),
+				civil::time(17, 30, 0, 0)
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_invalid_window() -> Result<()> {
+		assert_eq!(
+			every(10)
+				.minutes()?
+				.between("9am", "17:00")
+				.unwrap_err()
+				.to_string(),
+			"Invalid time format for between(): 9am".to_string()
+		);
+		assert_eq!(
+			every(10)
+				.minutes()?
+				.between("09:00", "09:00")
+				.unwrap_err()
+				.to_string(),
+			"Time window must not start and end at the same time".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_reject_invalid_jitter() -> Result<()> {
+		assert!(every(1).hours()?.jitter(0.seconds()).is_err());
+		assert!(every(1).hours()?.jitter((-5).seconds()).is_err());
+		assert!(every(1).hours()?.jitter_percent(0.0).is_err());
+		assert!(every(1).hours()?.jitter_percent(150.0).is_err());
+		assert!(every(1).hours()?.jitter_percent(f64::NAN).is_err());
+		assert!(every(1).hours()?.jitter_percent(0.001).is_err());
+		assert_eq!(
+			every(1).hours()?.jitter_percent(12.345)?.jitter,
+			Some(Jitter::BasisPoints(1235))
+		);
+		assert!(every(1).hours()?.jitter_percent(100.0).is_ok());
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_upcoming_seeded() -> Result<()> {
+		let from = "2024-01-01T07:00[America/New_York]".parse::<Zoned>()?;
+		let job = every(1).to(1000)?.minutes()?.rng_seed(7);
+		let runs: Vec<Zoned> = job.upcoming(&from).take(4).collect();
+		let gaps: HashSet<i64> = runs
+			.windows(2)
+			.map(|pair| pair[0].duration_until(&pair[1]).as_secs())
+			.collect();
+		assert_eq!(gaps.len(), 3);
+		assert_eq!(job.upcoming(&from).take(4).collect::<Vec<_>>(), runs);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_stable_hash() {
+		assert_eq!(stable_hash(""), 0xcbf2_9ce4_8422_2325);
+		assert_eq!(stable_hash("a"), 0xaf63_dc4c_8601_ec8c);
+	}
+
 	#[test]
 	#[cfg(feature = "random")]
 	fn test_latest_greater_than_interval() {
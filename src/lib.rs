@@ -17,7 +17,7 @@
 //! ```
 //! Instantiate a `Scheduler` and schedule jobs:
 //! ```rust
-//! # use skedge::{Scheduler, every, every_single};
+//! # use skedge::{Scheduler, after, every, every_single};
 //! # use jiff::{ToSpan as _, Zoned};
 //! # use std::time::Duration;
 //! # use std::thread::sleep;
@@ -47,6 +47,8 @@
 //!
 //! every_single().minute()?.at(":17")?.run(&mut schedule, job)?;
 //!
+//! after(45.seconds()).run(&mut schedule, job)?;
+//!
 //! #[cfg(feature = "random")]
 //! every(2)
 //!     .to(8)?
@@ -71,19 +73,36 @@
 
 #![warn(clippy::pedantic)]
 
+mod calendar;
 mod callable;
 mod error;
+mod ics;
 mod job;
+mod on_calendar;
+mod rrule;
+mod schedule;
 mod scheduler;
+mod selector;
 mod time;
 
+pub use calendar::Calendar;
 use callable::{
-	Callable, FiveToUnit, FourToUnit, OneToUnit, SixToUnit, ThreeToUnit, TwoToUnit, UnitToUnit,
+	Adaptive, Callable, FiveToUnit, FourToUnit, OneToUnit, SixToUnit, ThreeToUnit, TwoToUnit,
+	UnitToUnit,
 };
 pub use error::*;
-pub use job::{every, every_single, Interval, Job, Tag};
-pub use scheduler::Scheduler;
-use time::{Clock, Timekeeper, Unit};
+#[cfg(feature = "random")]
+pub use job::JitterMode;
+pub use job::{
+	after, at_datetime, every, every_single, HolidayPolicy, Interval, Job, ScheduleMode, Tag,
+};
+pub use on_calendar::OnCalendar;
+use rrule::Cursor;
+pub use rrule::{Frequency, RRule};
+pub use schedule::{Except, Or, Schedule, Window, Within};
+pub use scheduler::{ResumePolicy, Scheduler, SimulatedRun, TriggerMode, TriggeredRun};
+pub use selector::Selector;
+use time::{Clock, TimeWindow, Timekeeper, Unit};
 
 #[cfg(feature = "ffi")]
 mod ffi;
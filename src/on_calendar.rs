@@ -0,0 +1,509 @@
+//! systemd `OnCalendar=` calendar event expressions, for timers moved out of systemd.
+
+use crate::{Error, Result, Schedule};
+use jiff::{
+	civil::{self, Weekday},
+	tz::TimeZone,
+	Zoned,
+};
+use std::{fmt, str::FromStr};
+
+/// Give up looking for a matching date after checking this many days
+const MAX_DAYS: usize = 100_000;
+
+/// Every weekday, in the order systemd lists them
+const WEEKDAYS: [(Weekday, &str, &str); 7] = [
+	(Weekday::Monday, "Mon", "Monday"),
+	(Weekday::Tuesday, "Tue", "Tuesday"),
+	(Weekday::Wednesday, "Wed", "Wednesday"),
+	(Weekday::Thursday, "Thu", "Thursday"),
+	(Weekday::Friday, "Fri", "Friday"),
+	(Weekday::Saturday, "Sat", "Saturday"),
+	(Weekday::Sunday, "Sun", "Sunday"),
+];
+
+/// One comma-separated item of a component, like `5`, `1..4`, or `0/15`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Item {
+	start: i16,
+	end: Option<i16>,
+	step: Option<i16>,
+}
+
+impl Item {
+	fn matches(self, value: i16, max: i16) -> bool {
+		let end = match (self.end, self.step) {
+			(Some(end), _) => end,
+			(None, Some(_)) => max,
+			(None, None) => self.start,
+		};
+		(self.start..=end).contains(&value) && (value - self.start) % self.step.unwrap_or(1) == 0
+	}
+}
+
+/// A date or time component.  `None` is a wildcard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Component {
+	items: Option<Vec<Item>>,
+	min: i16,
+	max: i16,
+	width: usize,
+}
+
+impl Component {
+	fn wildcard(min: i16, max: i16, width: usize) -> Self {
+		Self {
+			items: None,
+			min,
+			max,
+			width,
+		}
+	}
+
+	fn exactly(value: i16, min: i16, max: i16, width: usize) -> Self {
+		let item = Item {
+			start: value,
+			end: None,
+			step: None,
+		};
+		Self {
+			items: Some(vec![item]),
+			min,
+			max,
+			width,
+		}
+	}
+
+	/// Parse something like `*`, `01,15`, `1..5`, or `*/2`
+	fn parse(source: &str, min: i16, max: i16, width: usize) -> Result<Self> {
+		let mut component = Self::wildcard(min, max, width);
+		if source == "*" {
+			return Ok(component);
+		}
+		let number = |value: &str| -> Result<i16> {
+			match value.parse() {
+				Ok(n) if (min..=max).contains(&n) => Ok(n),
+				_ => Err(reason(format!("{value} is not between {min} and {max}"))),
+			}
+		};
+		let mut items = Vec::new();
+		for item in source.split(',') {
+			let (range, step) = match item.split_once('/') {
+				Some((range, step)) => match step.parse() {
+					Ok(step) if step > 0 => (range, Some(step)),
+					_ => return Err(reason(format!("invalid repetition {item}"))),
+				},
+				None => (item, None),
+			};
+			let (start, end) = match range.split_once("..") {
+				_ if range == "*" => (min, None),
+				Some((start, end)) => (number(start)?, Some(number(end)?)),
+				None => (number(range)?, None),
+			};
+			if end.is_some_and(|end| end < start) {
+				return Err(reason(format!("range {range} ends before it starts")));
+			}
+			items.push(Item { start, end, step });
+		}
+		component.items = Some(items);
+		Ok(component)
+	}
+
+	fn matches(&self, value: i16) -> bool {
+		self.items.as_ref().map_or(true, |items| {
+			items.iter().any(|item| item.matches(value, self.max))
+		})
+	}
+
+	/// Smallest matching value that's at least `from`
+	fn first_from(&self, from: i16) -> Option<i16> {
+		(from..=self.max).find(|&value| self.matches(value))
+	}
+}
+
+impl fmt::Display for Component {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let Some(ref items) = self.items else {
+			return write!(f, "*");
+		};
+		let width = self.width;
+		for (idx, item) in items.iter().enumerate() {
+			if idx > 0 {
+				write!(f, ",")?;
+			}
+			write!(f, "{:0width$}", item.start)?;
+			if let Some(end) = item.end {
+				write!(f, "..{end:0width$}")?;
+			}
+			if let Some(step) = item.step {
+				write!(f, "/{step}")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A systemd calendar event expression, as used by `OnCalendar=` in timer units.
+///
+/// Supports weekday lists and ranges, `*` wildcards, `,` lists, `..` ranges, `/` repetition,
+/// a trailing time zone, and the `minutely` through `yearly` shorthands.  Formats back to
+/// the normalized form printed by `systemd-analyze calendar`.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn main() -> Result<()> {
+/// let event: OnCalendar = "mon..fri 9:30".parse()?;
+/// assert_eq!(event.to_string(), "Mon..Fri *-*-* 09:30:00");
+/// let event: OnCalendar = "*:0/15".parse()?;
+/// assert_eq!(event.to_string(), "*-*-* *:00/15:00");
+/// let event: OnCalendar = "monthly".parse()?;
+/// assert_eq!(event.to_string(), "*-*-01 00:00:00");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnCalendar {
+	/// Allowed weekdays, indexed from Monday.  All false means any weekday.
+	weekdays: [bool; 7],
+	year: Component,
+	month: Component,
+	day: Component,
+	hour: Component,
+	minute: Component,
+	second: Component,
+	/// Time zone the expression is evaluated in, instead of the local one
+	time_zone: Option<(String, TimeZone)>,
+}
+
+impl Schedule for OnCalendar {
+	/// Find the first matching moment strictly after `after`, or `None` if there isn't one.
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		let time_zone = self
+			.time_zone
+			.as_ref()
+			.map_or_else(|| after.time_zone().clone(), |(_, tz)| tz.clone());
+		let local = after.with_time_zone(time_zone.clone()).datetime();
+
+		let mut date = local.date();
+		for _ in 0..MAX_DAYS {
+			if !self.date_matches(date) {
+				date = date.tomorrow().ok()?;
+				continue;
+			}
+			// Only today needs to look past the current time
+			let earliest = if date == local.date() {
+				Some(local.time())
+			} else {
+				None
+			};
+			if let Some(time) = self.first_time(earliest) {
+				let next = date.to_datetime(time).to_zoned(time_zone.clone()).ok()?;
+				if next > *after {
+					return Some(next.with_time_zone(after.time_zone().clone()));
+				}
+			}
+			date = date.tomorrow().ok()?;
+		}
+		None
+	}
+
+	fn describe(&self) -> String {
+		self.to_string()
+	}
+}
+
+impl OnCalendar {
+	fn date_matches(&self, date: civil::Date) -> bool {
+		let weekday = usize::from(date.weekday().to_monday_zero_offset().unsigned_abs());
+		(self.weekdays.iter().all(|&on| !on) || self.weekdays[weekday])
+			&& self.year.matches(date.year())
+			&& self.month.matches(i16::from(date.month()))
+			&& self.day.matches(i16::from(date.day()))
+	}
+
+	/// First matching time of day strictly after `earliest`, or from midnight
+	fn first_time(&self, earliest: Option<civil::Time>) -> Option<civil::Time> {
+		let time = |hour: i16, minute: i16, second: i16| {
+			civil::Time::new(
+				i8::try_from(hour).ok()?,
+				i8::try_from(minute).ok()?,
+				i8::try_from(second).ok()?,
+				0,
+			)
+			.ok()
+		};
+		let mut hour = self.hour.first_from(self.hour.min)?;
+		loop {
+			let mut minute = self.minute.first_from(self.minute.min);
+			while let Some(m) = minute {
+				let mut second = self.second.first_from(self.second.min);
+				while let Some(s) = second {
+					let candidate = time(hour, m, s)?;
+					if earliest.map_or(true, |earliest| candidate > earliest) {
+						return Some(candidate);
+					}
+					second = self.second.first_from(s + 1);
+				}
+				minute = self.minute.first_from(m + 1);
+			}
+			hour = self.hour.first_from(hour + 1)?;
+		}
+	}
+
+	/// Expand a shorthand like `daily` into the full expression
+	fn expand_shorthand(source: &str) -> Option<&'static str> {
+		let expanded = match source.to_ascii_lowercase().as_str() {
+			"minutely" => "*-*-* *:*:00",
+			"hourly" => "*-*-* *:00:00",
+			"daily" => "*-*-* 00:00:00",
+			"weekly" => "Mon *-*-* 00:00:00",
+			"monthly" => "*-*-01 00:00:00",
+			"quarterly" => "*-01,04,07,10-01 00:00:00",
+			"semiannually" => "*-01,07-01 00:00:00",
+			"yearly" | "annually" => "*-01-01 00:00:00",
+			_ => return None,
+		};
+		Some(expanded)
+	}
+
+	/// Parse the optional weekday, date, and time tokens, in that order
+	fn parse_tokens(&mut self, tokens: &[&str]) -> Result<()> {
+		let mut tokens = tokens.iter().copied().peekable();
+		if let Some(weekdays) = tokens.next_if(|token| token.starts_with(char::is_alphabetic)) {
+			self.parse_weekdays(weekdays)?;
+		}
+		if let Some(date) = tokens.next_if(|token| !token.contains(':')) {
+			self.parse_date(date)?;
+		}
+		if let Some(time) = tokens.next() {
+			self.parse_time(time)?;
+		}
+		match tokens.next() {
+			Some(extra) => Err(reason(format!("unexpected {extra}"))),
+			None => Ok(()),
+		}
+	}
+
+	fn parse_weekdays(&mut self, source: &str) -> Result<()> {
+		let weekday = |name: &str| {
+			WEEKDAYS
+				.iter()
+				.position(|(_, short, long)| {
+					name.eq_ignore_ascii_case(short) || name.eq_ignore_ascii_case(long)
+				})
+				.ok_or_else(|| reason(format!("unknown weekday {name}")))
+		};
+		for item in source.split(',') {
+			match item.split_once("..") {
+				Some((first, last)) => {
+					let (first, last) = (weekday(first)?, weekday(last)?);
+					// Ranges like `Sat..Mon` wrap around the end of the week
+					let mut idx = first;
+					self.weekdays[idx] = true;
+					while idx != last {
+						idx = (idx + 1) % 7;
+						self.weekdays[idx] = true;
+					}
+				},
+				None => self.weekdays[weekday(item)?] = true,
+			}
+		}
+		Ok(())
+	}
+
+	fn parse_date(&mut self, source: &str) -> Result<()> {
+		let parts: Vec<&str> = source.split('-').collect();
+		let (year, month, day) = match parts[..] {
+			[year, month, day] => (Some(year), month, day),
+			[month, day] => (None, month, day),
+			_ => return Err(reason(format!("invalid date {source}"))),
+		};
+		if let Some(year) = year {
+			self.year = Component::parse(year, 1970, 9999, 4)?;
+		}
+		self.month = Component::parse(month, 1, 12, 2)?;
+		self.day = Component::parse(day, 1, 31, 2)?;
+		Ok(())
+	}
+
+	fn parse_time(&mut self, source: &str) -> Result<()> {
+		let parts: Vec<&str> = source.split(':').collect();
+		let (hour, minute, second) = match parts[..] {
+			[hour, minute, second] => (hour, minute, Some(second)),
+			[hour, minute] => (hour, minute, None),
+			_ => return Err(reason(format!("invalid time {source}"))),
+		};
+		self.hour = Component::parse(hour, 0, 23, 2)?;
+		self.minute = Component::parse(minute, 0, 59, 2)?;
+		if let Some(second) = second {
+			self.second = Component::parse(second, 0, 59, 2)?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for OnCalendar {
+	type Err = Error;
+
+	fn from_str(source: &str) -> Result<Self> {
+		let source = source.trim();
+		let expression = OnCalendar::expand_shorthand(source).unwrap_or(source);
+		let mut event = OnCalendar {
+			weekdays: [false; 7],
+			year: Component::wildcard(1970, 9999, 4),
+			month: Component::wildcard(1, 12, 2),
+			day: Component::wildcard(1, 31, 2),
+			hour: Component::exactly(0, 0, 23, 2),
+			minute: Component::exactly(0, 0, 59, 2),
+			second: Component::exactly(0, 0, 59, 2),
+			time_zone: None,
+		};
+
+		let mut tokens: Vec<&str> = expression.split_whitespace().collect();
+		if tokens.is_empty() {
+			return Err(invalid(source, "empty expression".to_string()));
+		}
+		// A trailing time zone can't be mistaken for a date or time
+		if let Some(&last) = tokens.last() {
+			if !last.contains([':', '-', '*']) {
+				if let Ok(time_zone) = TimeZone::get(last) {
+					event.time_zone = Some((last.to_string(), time_zone));
+					tokens.pop();
+				}
+			}
+		}
+
+		event.parse_tokens(&tokens).map_err(|err| match err {
+			Error::InvalidCalendarEvent(_, reason) => invalid(source, reason),
+			other => other,
+		})?;
+		Ok(event)
+	}
+}
+
+impl fmt::Display for OnCalendar {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		// Runs of three or more weekdays collapse into a range
+		let mut runs: Vec<(usize, usize)> = Vec::new();
+		for idx in (0..7).filter(|&idx| self.weekdays[idx]) {
+			match runs.last_mut() {
+				Some((_, last)) if *last + 1 == idx => *last = idx,
+				_ => runs.push((idx, idx)),
+			}
+		}
+		let weekdays = runs
+			.into_iter()
+			.flat_map(|(first, last)| {
+				let name = |idx: usize| WEEKDAYS[idx].1.to_string();
+				if last - first >= 2 {
+					vec![format!("{}..{}", name(first), name(last))]
+				} else {
+					(first..=last).map(name).collect()
+				}
+			})
+			.collect::<Vec<_>>();
+		if !weekdays.is_empty() {
+			write!(f, "{} ", weekdays.join(","))?;
+		}
+		write!(
+			f,
+			"{}-{}-{} {}:{}:{}",
+			self.year, self.month, self.day, self.hour, self.minute, self.second
+		)?;
+		if let Some((ref name, _)) = self.time_zone {
+			write!(f, " {name}")?;
+		}
+		Ok(())
+	}
+}
+
+/// A parse failure before the offending expression is known
+fn reason(reason: String) -> Error {
+	Error::InvalidCalendarEvent(String::new(), reason)
+}
+
+/// Construct a new invalid calendar event error
+fn invalid(expression: &str, reason: String) -> Error {
+	Error::InvalidCalendarEvent(expression.to_string(), reason)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_normalize() -> Result<()> {
+		let normalized =
+			|source: &str| -> Result<String> { Ok(source.parse::<OnCalendar>()?.to_string()) };
+		assert_eq!(
+			normalized("Mon..Fri *-*-* 09:00:00")?,
+			"Mon..Fri *-*-* 09:00:00"
+		);
+		assert_eq!(normalized("Sat,Sunday 8:05")?, "Sat,Sun *-*-* 08:05:00");
+		assert_eq!(
+			normalized("Mon,Tue,Wed,Fri 2025-*-1,15 12:00")?,
+			"Mon..Wed,Fri 2025-*-01,15 12:00:00"
+		);
+		assert_eq!(normalized("*-*-* *:*:0/20")?, "*-*-* *:*:00/20");
+		assert_eq!(normalized("12-25")?, "*-12-25 00:00:00");
+		assert_eq!(normalized("quarterly")?, "*-01,04,07,10-01 00:00:00");
+		assert_eq!(
+			normalized("Fri..Mon 17:00 America/Chicago")?,
+			"Mon,Fri..Sun *-*-* 17:00:00 America/Chicago"
+		);
+		// Normalized forms parse back to the same event
+		let event: OnCalendar = "Mon..Wed,Fri 2025-*-01,15 12:00:00 UTC".parse()?;
+		assert_eq!(event.to_string().parse::<OnCalendar>()?, event);
+		Ok(())
+	}
+
+	#[test]
+	fn test_next_after() -> Result<()> {
+		let next = |source: &str, from: &str| -> Result<String> {
+			let event: OnCalendar = source.parse()?;
+			let from: Zoned = from.parse()?;
+			Ok(event.next_after(&from).unwrap().to_string())
+		};
+		// Friday evening rolls over to Monday
+		assert_eq!(
+			next("Mon..Fri 09:00", "2024-01-05T17:00[America/New_York]")?,
+			"2024-01-08T09:00:00-05:00[America/New_York]"
+		);
+		assert_eq!(
+			next("*:0/15", "2024-01-05T17:00[America/New_York]")?,
+			"2024-01-05T17:15:00-05:00[America/New_York]"
+		);
+		assert_eq!(
+			next("*-02-29 12:00", "2024-03-01T00:00[UTC]")?,
+			"2028-02-29T12:00:00+00:00[UTC]"
+		);
+		// Evaluated in the expression's time zone, reported in the caller's
+		assert_eq!(
+			next("09:00 Europe/London", "2024-01-05T00:00[America/New_York]")?,
+			"2024-01-05T04:00:00-05:00[America/New_York]"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_invalid() {
+		let error = |source: &str| source.parse::<OnCalendar>().unwrap_err().to_string();
+		assert_eq!(
+			error("Mon..Fry 09:00"),
+			"Invalid calendar event \"Mon..Fry 09:00\": unknown weekday Fry".to_string()
+		);
+		assert_eq!(
+			error("*-13-01"),
+			"Invalid calendar event \"*-13-01\": 13 is not between 1 and 12".to_string()
+		);
+		assert_eq!(
+			error("*:0/0"),
+			"Invalid calendar event \"*:0/0\": invalid repetition 0/0".to_string()
+		);
+		assert_eq!(
+			error("09:00 10:00"),
+			"Invalid calendar event \"09:00 10:00\": unexpected 10:00".to_string()
+		);
+	}
+}
@@ -0,0 +1,844 @@
+//! RFC 5545 recurrence rules, for schedules handed over in iCalendar `RRULE` form.
+
+use crate::{Error, Result, Schedule};
+use jiff::{
+	civil::{self, Weekday},
+	tz::TimeZone,
+	Span, Timestamp, ToSpan as _, Zoned,
+};
+use std::{fmt, str::FromStr};
+
+/// Give up looking for the next occurrence after this many periods in a row without one
+const MAX_EMPTY_PERIODS: u32 = 100_000;
+
+/// How often a recurrence rule repeats, from the `FREQ` part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+	Secondly,
+	Minutely,
+	Hourly,
+	Daily,
+	Weekly,
+	Monthly,
+	Yearly,
+}
+
+impl Frequency {
+	/// The span covered by `count` periods of this frequency
+	fn span(self, count: i64) -> Result<Span> {
+		let span = match self {
+			Frequency::Secondly => Span::new().try_seconds(count)?,
+			Frequency::Minutely => Span::new().try_minutes(count)?,
+			Frequency::Hourly => Span::new().try_hours(count)?,
+			Frequency::Daily => Span::new().try_days(count)?,
+			Frequency::Weekly => Span::new().try_weeks(count)?,
+			Frequency::Monthly => Span::new().try_months(count)?,
+			Frequency::Yearly => Span::new().try_years(count)?,
+		};
+		Ok(span)
+	}
+}
+
+impl FromStr for Frequency {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		match s.to_ascii_uppercase().as_str() {
+			"SECONDLY" => Ok(Frequency::Secondly),
+			"MINUTELY" => Ok(Frequency::Minutely),
+			"HOURLY" => Ok(Frequency::Hourly),
+			"DAILY" => Ok(Frequency::Daily),
+			"WEEKLY" => Ok(Frequency::Weekly),
+			"MONTHLY" => Ok(Frequency::Monthly),
+			"YEARLY" => Ok(Frequency::Yearly),
+			_ => Err(invalid(format!("unknown FREQ {s}"))),
+		}
+	}
+}
+
+impl fmt::Display for Frequency {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let name = match self {
+			Frequency::Secondly => "SECONDLY",
+			Frequency::Minutely => "MINUTELY",
+			Frequency::Hourly => "HOURLY",
+			Frequency::Daily => "DAILY",
+			Frequency::Weekly => "WEEKLY",
+			Frequency::Monthly => "MONTHLY",
+			Frequency::Yearly => "YEARLY",
+		};
+		write!(f, "{name}")
+	}
+}
+
+/// A moment written as an iCalendar `DATE` or `DATE-TIME` value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Moment {
+	/// `YYYYMMDDTHHMMSSZ`
+	Utc(Timestamp),
+	/// `YYYYMMDDTHHMMSS`, in the rule's time zone
+	Local(civil::DateTime),
+	/// `YYYYMMDD`
+	Day(civil::Date),
+}
+
+impl Moment {
+	fn parse(value: &str) -> Result<Self> {
+		let parse_error = || invalid(format!("invalid date {value}"));
+		if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+			let datetime =
+				civil::DateTime::strptime("%Y%m%dT%H%M%S", utc).map_err(|_| parse_error())?;
+			Ok(Moment::Utc(datetime.to_zoned(TimeZone::UTC)?.timestamp()))
+		} else if value.contains(['T', 't']) {
+			let datetime =
+				civil::DateTime::strptime("%Y%m%dT%H%M%S", value).map_err(|_| parse_error())?;
+			Ok(Moment::Local(datetime))
+		} else {
+			let date = civil::Date::strptime("%Y%m%d", value).map_err(|_| parse_error())?;
+			Ok(Moment::Day(date))
+		}
+	}
+
+	/// Check whether an occurrence falls after this moment.  Dates cover the whole day.
+	fn is_before(&self, occurrence: &Zoned) -> bool {
+		match self {
+			Moment::Utc(timestamp) => occurrence.timestamp() > *timestamp,
+			Moment::Local(datetime) => occurrence.datetime() > *datetime,
+			Moment::Day(date) => occurrence.date() > *date,
+		}
+	}
+
+	/// Check whether an occurrence is exactly this moment.  Dates match the whole day.
+	fn matches(&self, occurrence: &Zoned) -> bool {
+		match self {
+			Moment::Utc(timestamp) => occurrence.timestamp() == *timestamp,
+			Moment::Local(datetime) => occurrence.datetime() == *datetime,
+			Moment::Day(date) => occurrence.date() == *date,
+		}
+	}
+}
+
+/// An RFC 5545 recurrence rule, with optional `DTSTART` and `EXDATE` properties.
+///
+/// Supports every frequency, `INTERVAL`, `COUNT`, `UNTIL`, `WKST`, and the `BYMONTH`,
+/// `BYMONTHDAY`, `BYDAY`, `BYHOUR`, `BYMINUTE`, and `BYSECOND` rules.  Without a `DTSTART`,
+/// the rule starts when the job is scheduled, in the local time zone.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn main() -> Result<()> {
+/// use jiff::Zoned;
+/// let rule: RRule = "DTSTART;TZID=America/New_York:20240101T090000\n\
+///                    RRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4\n\
+///                    EXDATE;TZID=America/New_York:20240103T090000"
+///     .parse()?;
+/// let from: Zoned = "2024-01-02T00:00[America/New_York]".parse()?;
+/// let next = rule.next_after(&from).unwrap();
+/// assert_eq!(next.to_string(), "2024-01-08T09:00:00-05:00[America/New_York]");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+	/// First occurrence, and source of defaults for missing `BYxxx` rules
+	dtstart: Option<civil::DateTime>,
+	/// Time zone occurrences are computed in
+	time_zone: Option<TimeZone>,
+	freq: Frequency,
+	interval: i64,
+	count: Option<u32>,
+	until: Option<Moment>,
+	by_month: Vec<i8>,
+	by_month_day: Vec<i8>,
+	/// Weekdays, optionally numbered within the month or year, like `-1FR`
+	by_day: Vec<(Option<i8>, Weekday)>,
+	by_hour: Vec<i8>,
+	by_minute: Vec<i8>,
+	by_second: Vec<i8>,
+	week_start: Weekday,
+	exdates: Vec<Moment>,
+}
+
+impl Schedule for RRule {
+	/// Find the first occurrence strictly after `after`, or `None` if the rule has run out.
+	///
+	/// A rule without a `DTSTART` starts at `after` when evaluated on its own.  Jobs pin it to
+	/// the moment they're added to a scheduler instead.
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		self.next_from(after, &mut Cursor::default())
+	}
+
+	fn describe(&self) -> String {
+		self.to_string()
+	}
+}
+
+/// Where a search through a rule left off, so later searches don't start over from `DTSTART`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cursor {
+	/// Period the next search starts in
+	period: i64,
+	/// Occurrences in the periods before `period`, including excluded ones
+	seen: u32,
+}
+
+impl RRule {
+	/// Find the first occurrence strictly after `after`, continuing from `cursor` and moving it
+	/// to the period of the occurrence found
+	pub(crate) fn next_from(&self, after: &Zoned, cursor: &mut Cursor) -> Option<Zoned> {
+		let time_zone = self
+			.time_zone
+			.clone()
+			.unwrap_or_else(|| after.time_zone().clone());
+		let start = self
+			.dtstart
+			.unwrap_or_else(|| whole_seconds(after.datetime()));
+		let local_after = after.with_time_zone(time_zone.clone()).datetime();
+
+		if self.period_start(start, cursor.period).ok()? > local_after {
+			*cursor = Cursor::default();
+		}
+		// Without a COUNT, earlier periods don't matter and can be skipped entirely
+		if self.count.is_none() {
+			cursor.period = cursor
+				.period
+				.max(self.periods_between(start, local_after)? - 1);
+		}
+
+		// With a COUNT, the search ends after COUNT occurrences, so only a long stretch of
+		// periods without any needs a limit
+		let mut empty_periods = 0;
+		while empty_periods < MAX_EMPTY_PERIODS {
+			let period = self.period_start(start, cursor.period).ok()?;
+			let mut seen = cursor.seen;
+			for candidate in self.expand(period, start) {
+				if candidate < start {
+					continue;
+				}
+				let occurrence = candidate.to_zoned(time_zone.clone()).ok()?;
+				if self.until.is_some_and(|until| until.is_before(&occurrence)) {
+					return None;
+				}
+				// Excluded occurrences still count towards COUNT
+				seen = seen.saturating_add(1);
+				if self.count.is_some_and(|count| seen > count) {
+					return None;
+				}
+				if occurrence > *after && !self.exdates.iter().any(|ex| ex.matches(&occurrence)) {
+					return Some(occurrence);
+				}
+			}
+			empty_periods = if seen == cursor.seen {
+				empty_periods + 1
+			} else {
+				0
+			};
+			cursor.seen = seen;
+			cursor.period += 1;
+		}
+		None
+	}
+
+	/// Fill in a missing `DTSTART` and time zone from `now`
+	pub(crate) fn anchor(&mut self, now: &Zoned) {
+		if self.time_zone.is_none() {
+			self.time_zone = Some(now.time_zone().clone());
+		}
+		if self.dtstart.is_none() {
+			self.dtstart = Some(whole_seconds(now.datetime()));
+		}
+	}
+
+	/// Number of whole periods from the one containing `start` to the one containing `when`
+	fn periods_between(&self, start: civil::DateTime, when: civil::DateTime) -> Option<i64> {
+		if when <= start {
+			return Some(0);
+		}
+		let elapsed = match self.freq {
+			Frequency::Yearly => i64::from(when.year() - start.year()),
+			Frequency::Monthly => {
+				i64::from(when.year() - start.year()) * 12 + i64::from(when.month() - start.month())
+			},
+			Frequency::Weekly => {
+				let from = week_start(start.date(), self.week_start).ok()?;
+				let to = week_start(when.date(), self.week_start).ok()?;
+				from.duration_until(to).as_hours() / (24 * 7)
+			},
+			Frequency::Daily => start.date().duration_until(when.date()).as_hours() / 24,
+			Frequency::Hourly => start.duration_until(when).as_secs() / 3600,
+			Frequency::Minutely => start.duration_until(when).as_secs() / 60,
+			Frequency::Secondly => start.duration_until(when).as_secs(),
+		};
+		Some(elapsed / self.interval)
+	}
+
+	/// First moment of the `index`th period of the rule
+	fn period_start(&self, start: civil::DateTime, index: i64) -> Result<civil::DateTime> {
+		let origin = match self.freq {
+			Frequency::Yearly => start
+				.date()
+				.first_of_year()
+				.to_datetime(civil::Time::midnight()),
+			Frequency::Monthly => start
+				.date()
+				.first_of_month()
+				.to_datetime(civil::Time::midnight()),
+			Frequency::Weekly => {
+				week_start(start.date(), self.week_start)?.to_datetime(civil::Time::midnight())
+			},
+			Frequency::Daily => start.date().to_datetime(civil::Time::midnight()),
+			Frequency::Hourly => start
+				.with()
+				.minute(0)
+				.second(0)
+				.subsec_nanosecond(0)
+				.build()?,
+			Frequency::Minutely => start.with().second(0).subsec_nanosecond(0).build()?,
+			Frequency::Secondly => start.with().subsec_nanosecond(0).build()?,
+		};
+		Ok(origin.checked_add(self.freq.span(index * self.interval)?)?)
+	}
+
+	/// Every candidate occurrence within the period starting at `period`, in order
+	fn expand(&self, period: civil::DateTime, start: civil::DateTime) -> Vec<civil::DateTime> {
+		let days = match self.freq {
+			Frequency::Yearly => period.date().days_in_year(),
+			Frequency::Monthly => i16::from(period.date().days_in_month()),
+			Frequency::Weekly => 7,
+			_ => 1,
+		};
+		let dates = (0..days)
+			.filter_map(|offset| period.date().checked_add(offset.days()).ok())
+			.filter(|&date| self.date_matches(date, start.date()));
+
+		// Units finer than the frequency come from BYxxx rules or DTSTART, coarser ones are fixed
+		let expand = |fixed: bool, current: i8, rule: &[i8], default: i8| -> Vec<i8> {
+			if fixed {
+				[current]
+					.into_iter()
+					.filter(|value| rule.is_empty() || rule.contains(value))
+					.collect()
+			} else if rule.is_empty() {
+				vec![default]
+			} else {
+				rule.to_vec()
+			}
+		};
+		let hours = expand(
+			self.freq <= Frequency::Hourly,
+			period.hour(),
+			&self.by_hour,
+			start.hour(),
+		);
+		let minutes = expand(
+			self.freq <= Frequency::Minutely,
+			period.minute(),
+			&self.by_minute,
+			start.minute(),
+		);
+		let seconds = expand(
+			self.freq == Frequency::Secondly,
+			period.second(),
+			&self.by_second,
+			start.second(),
+		);
+
+		let mut candidates = Vec::new();
+		for date in dates {
+			for &hour in &hours {
+				for &minute in &minutes {
+					for &second in &seconds {
+						if let Ok(time) = civil::Time::new(hour, minute, second, 0) {
+							candidates.push(date.to_datetime(time));
+						}
+					}
+				}
+			}
+		}
+		candidates.sort_unstable();
+		candidates
+	}
+
+	/// Check a date against the `BYMONTH`, `BYMONTHDAY`, and `BYDAY` rules
+	fn date_matches(&self, date: civil::Date, start: civil::Date) -> bool {
+		if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+			return false;
+		}
+		let days_in_month = date.days_in_month();
+		if !self.by_month_day.is_empty()
+			&& !self
+				.by_month_day
+				.iter()
+				.any(|&day| day == date.day() || day == date.day() - days_in_month - 1)
+		{
+			return false;
+		}
+		if !self.by_day.is_empty() {
+			// Numbered weekdays count within the year, unless the rule is limited to months
+			let within_year = self.freq == Frequency::Yearly && self.by_month.is_empty();
+			let (position, length) = if within_year {
+				(date.day_of_year(), date.days_in_year())
+			} else {
+				(i16::from(date.day()), i16::from(days_in_month))
+			};
+			let nth = (position - 1) / 7 + 1;
+			let nth_last = -((length - position) / 7 + 1);
+			let matches = self.by_day.iter().any(|&(ordinal, weekday)| {
+				weekday == date.weekday()
+					&& ordinal.map_or(true, |n| i16::from(n) == nth || i16::from(n) == nth_last)
+			});
+			if !matches {
+				return false;
+			}
+		}
+
+		// Without any day rules, the day comes from DTSTART
+		if self.by_month_day.is_empty() && self.by_day.is_empty() {
+			return match self.freq {
+				Frequency::Yearly => {
+					date.day() == start.day()
+						&& (!self.by_month.is_empty() || date.month() == start.month())
+				},
+				Frequency::Monthly => date.day() == start.day(),
+				Frequency::Weekly => date.weekday() == start.weekday(),
+				_ => true,
+			};
+		}
+		true
+	}
+
+	/// Parse the value of an `RRULE` property
+	fn parse_rule(&mut self, rule: &str) -> Result<()> {
+		let mut freq = None;
+		for part in rule.split(';').filter(|part| !part.is_empty()) {
+			let (key, value) = part
+				.split_once('=')
+				.ok_or_else(|| invalid(format!("malformed rule part {part}")))?;
+			let number = |value: &str| -> Result<i64> {
+				value
+					.parse()
+					.map_err(|_| invalid(format!("invalid number {value}")))
+			};
+			let list = |low: i64, high: i64, signed: bool| -> Result<Vec<i8>> {
+				value
+					.split(',')
+					.map(|item| {
+						let n = number(item)?;
+						let in_range =
+							(low..=high).contains(&n) || (signed && (-high..=-1).contains(&n));
+						if in_range {
+							Ok(i8::try_from(n).unwrap())
+						} else {
+							Err(invalid(format!("{key} value {item} out of range")))
+						}
+					})
+					.collect()
+			};
+			match key.to_ascii_uppercase().as_str() {
+				"FREQ" => freq = Some(value.parse()?),
+				"INTERVAL" => {
+					self.interval = number(value)?;
+					if self.interval < 1 {
+						return Err(invalid(format!("INTERVAL must be positive, got {value}")));
+					}
+				},
+				"COUNT" => {
+					let count = value
+						.parse()
+						.map_err(|_| invalid(format!("invalid COUNT {value}")))?;
+					if count == 0 {
+						return Err(invalid("COUNT must be positive".to_string()));
+					}
+					self.count = Some(count);
+				},
+				"UNTIL" => self.until = Some(Moment::parse(value)?),
+				"WKST" => self.week_start = parse_weekday(value)?,
+				"BYMONTH" => self.by_month = list(1, 12, false)?,
+				"BYMONTHDAY" => self.by_month_day = list(1, 31, true)?,
+				"BYHOUR" => self.by_hour = list(0, 23, false)?,
+				"BYMINUTE" => self.by_minute = list(0, 59, false)?,
+				"BYSECOND" => self.by_second = list(0, 59, false)?,
+				"BYDAY" => {
+					self.by_day = value
+						.split(',')
+						.map(parse_numbered_weekday)
+						.collect::<Result<_>>()?;
+				},
+				_ => return Err(invalid(format!("unsupported rule part {key}"))),
+			}
+		}
+		self.freq = freq.ok_or_else(|| invalid("missing FREQ".to_string()))?;
+
+		if self.count.is_some() && self.until.is_some() {
+			return Err(invalid(
+				"COUNT and UNTIL can't be used together".to_string(),
+			));
+		}
+		let numbered = self.by_day.iter().any(|(ordinal, _)| ordinal.is_some());
+		if numbered && ![Frequency::Monthly, Frequency::Yearly].contains(&self.freq) {
+			return Err(invalid(
+				"numbered BYDAY values need a MONTHLY or YEARLY rule".to_string(),
+			));
+		}
+		if !self.by_month_day.is_empty() && self.freq == Frequency::Weekly {
+			return Err(invalid(
+				"BYMONTHDAY can't be used with a WEEKLY rule".to_string(),
+			));
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for RRule {
+	type Err = Error;
+
+	/// Parse either a bare rule like `FREQ=DAILY;BYHOUR=9`, or `DTSTART`, `RRULE`, and `EXDATE`
+	/// properties on separate lines.
+	fn from_str(source: &str) -> Result<Self> {
+		let mut rrule = RRule {
+			dtstart: None,
+			time_zone: None,
+			freq: Frequency::Daily,
+			interval: 1,
+			count: None,
+			until: None,
+			by_month: Vec::new(),
+			by_month_day: Vec::new(),
+			by_day: Vec::new(),
+			by_hour: Vec::new(),
+			by_minute: Vec::new(),
+			by_second: Vec::new(),
+			week_start: Weekday::Monday,
+			exdates: Vec::new(),
+		};
+		let mut rule_part = None;
+
+		for line in source
+			.lines()
+			.map(str::trim)
+			.filter(|line| !line.is_empty())
+		{
+			let Some((name, value)) = line.split_once(':') else {
+				rule_part = Some(line);
+				continue;
+			};
+			let mut params = name.split(';');
+			let name = params.next().unwrap_or_default().to_ascii_uppercase();
+			let time_zone = params
+				.filter_map(|param| param.split_once('='))
+				.find(|(key, _)| key.eq_ignore_ascii_case("TZID"))
+				.map(|(_, tzid)| {
+					TimeZone::get(tzid).map_err(|_| invalid(format!("unknown TZID {tzid}")))
+				})
+				.transpose()?;
+			match name.as_str() {
+				"RRULE" => rule_part = Some(value),
+				"DTSTART" => {
+					let (dtstart, zone) = match Moment::parse(value)? {
+						Moment::Utc(timestamp) => (
+							timestamp.to_zoned(TimeZone::UTC).datetime(),
+							Some(TimeZone::UTC),
+						),
+						Moment::Local(datetime) => (datetime, time_zone),
+						Moment::Day(date) => (date.to_datetime(civil::Time::midnight()), time_zone),
+					};
+					rrule.dtstart = Some(dtstart);
+					rrule.time_zone = zone;
+				},
+				"EXDATE" => {
+					for exdate in value.split(',') {
+						rrule.exdates.push(Moment::parse(exdate)?);
+					}
+				},
+				_ => return Err(invalid(format!("unsupported property {name}"))),
+			}
+		}
+
+		let rule_part = rule_part.ok_or_else(|| invalid("missing RRULE".to_string()))?;
+		rrule.parse_rule(rule_part)?;
+		Ok(rrule)
+	}
+}
+
+impl fmt::Display for RRule {
+	/// Formats the rule part only, like `FREQ=WEEKLY;BYDAY=MO,WE`
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let join = |values: &[i8]| {
+			values
+				.iter()
+				.map(ToString::to_string)
+				.collect::<Vec<_>>()
+				.join(",")
+		};
+		write!(f, "FREQ={}", self.freq)?;
+		if self.interval != 1 {
+			write!(f, ";INTERVAL={}", self.interval)?;
+		}
+		if let Some(count) = self.count {
+			write!(f, ";COUNT={count}")?;
+		}
+		for (name, values) in [
+			("BYMONTH", &self.by_month),
+			("BYMONTHDAY", &self.by_month_day),
+		] {
+			if !values.is_empty() {
+				write!(f, ";{name}={}", join(values))?;
+			}
+		}
+		if !self.by_day.is_empty() {
+			let days = self
+				.by_day
+				.iter()
+				.map(|&(ordinal, weekday)| {
+					let ordinal = ordinal.map(|n| n.to_string()).unwrap_or_default();
+					format!("{ordinal}{}", weekday_code(weekday))
+				})
+				.collect::<Vec<_>>()
+				.join(",");
+			write!(f, ";BYDAY={days}")?;
+		}
+		for (name, values) in [
+			("BYHOUR", &self.by_hour),
+			("BYMINUTE", &self.by_minute),
+			("BYSECOND", &self.by_second),
+		] {
+			if !values.is_empty() {
+				write!(f, ";{name}={}", join(values))?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Construct a new invalid rule error
+fn invalid(reason: String) -> Error {
+	Error::InvalidRRule(reason)
+}
+
+/// The first day of the week containing `date`
+fn week_start(date: civil::Date, week_start: Weekday) -> Result<civil::Date> {
+	let offset = date.weekday().since(week_start);
+	Ok(date.checked_sub(i64::from(offset).days())?)
+}
+
+/// Drop the fraction of a second, since rules only go down to seconds
+fn whole_seconds(datetime: civil::DateTime) -> civil::DateTime {
+	datetime
+		.with()
+		.subsec_nanosecond(0)
+		.build()
+		.unwrap_or(datetime)
+}
+
+/// Parse a two-letter weekday code like `MO`
+fn parse_weekday(code: &str) -> Result<Weekday> {
+	let weekday = match code.to_ascii_uppercase().as_str() {
+		"MO" => Weekday::Monday,
+		"TU" => Weekday::Tuesday,
+		"WE" => Weekday::Wednesday,
+		"TH" => Weekday::Thursday,
+		"FR" => Weekday::Friday,
+		"SA" => Weekday::Saturday,
+		"SU" => Weekday::Sunday,
+		_ => return Err(invalid(format!("invalid weekday {code}"))),
+	};
+	Ok(weekday)
+}
+
+/// Parse a `BYDAY` value like `MO`, `2TU`, or `-1FR`
+fn parse_numbered_weekday(value: &str) -> Result<(Option<i8>, Weekday)> {
+	let split = value.len().saturating_sub(2);
+	let (ordinal, code) = value.split_at_checked(split).unwrap_or(("", value));
+	let weekday = parse_weekday(code)?;
+	if ordinal.is_empty() {
+		return Ok((None, weekday));
+	}
+	match ordinal.parse::<i8>() {
+		Ok(n) if n != 0 && (-53..=53).contains(&n) => Ok((Some(n), weekday)),
+		_ => Err(invalid(format!("invalid BYDAY value {value}"))),
+	}
+}
+
+/// The two-letter code for a weekday
+fn weekday_code(weekday: Weekday) -> &'static str {
+	match weekday {
+		Weekday::Monday => "MO",
+		Weekday::Tuesday => "TU",
+		Weekday::Wednesday => "WE",
+		Weekday::Thursday => "TH",
+		Weekday::Friday => "FR",
+		Weekday::Saturday => "SA",
+		Weekday::Sunday => "SU",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	/// Collect the first `n` occurrences after `from`
+	fn occurrences(rule: &str, from: &str, n: usize) -> Result<Vec<String>> {
+		let rule: RRule = rule.parse()?;
+		let mut when: Zoned = from.parse()?;
+		let mut found = Vec::new();
+		while found.len() < n {
+			let Some(next) = rule.next_after(&when) else {
+				break;
+			};
+			found.push(next.datetime().to_string());
+			when = next;
+		}
+		Ok(found)
+	}
+
+	#[test]
+	fn test_weekly() -> Result<()> {
+		assert_eq!(
+			occurrences(
+				"DTSTART;TZID=America/New_York:20240101T073000\n\
+				 RRULE:FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9",
+				"2024-01-01T00:00[America/New_York]",
+				4
+			)?,
+			vec![
+				"2024-01-01T09:30:00",
+				"2024-01-03T09:30:00",
+				"2024-01-08T09:30:00",
+				"2024-01-10T09:30:00",
+			]
+		);
+		// Every other week, skipping an excluded date
+		assert_eq!(
+			occurrences(
+				"DTSTART;TZID=America/New_York:20240102T180000\n\
+				 RRULE:FREQ=WEEKLY;INTERVAL=2\n\
+				 EXDATE;TZID=America/New_York:20240116T180000",
+				"2024-01-01T00:00[America/New_York]",
+				3
+			)?,
+			vec![
+				"2024-01-02T18:00:00",
+				"2024-01-30T18:00:00",
+				"2024-02-13T18:00:00",
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_monthly_and_yearly() -> Result<()> {
+		// Last Friday of the month
+		assert_eq!(
+			occurrences(
+				"DTSTART:20240101T120000Z\nRRULE:FREQ=MONTHLY;BYDAY=-1FR",
+				"2024-01-01T00:00[UTC]",
+				3
+			)?,
+			vec![
+				"2024-01-26T12:00:00",
+				"2024-02-23T12:00:00",
+				"2024-03-29T12:00:00",
+			]
+		);
+		// Months without a 31st are skipped
+		assert_eq!(
+			occurrences(
+				"DTSTART:20240131T000000Z\nRRULE:FREQ=MONTHLY",
+				"2024-01-01T00:00[UTC]",
+				3
+			)?,
+			vec![
+				"2024-01-31T00:00:00",
+				"2024-03-31T00:00:00",
+				"2024-05-31T00:00:00",
+			]
+		);
+		// US Thanksgiving
+		assert_eq!(
+			occurrences(
+				"DTSTART:20241128T000000Z\nRRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=4TH",
+				"2024-06-01T00:00[UTC]",
+				2
+			)?,
+			vec!["2024-11-28T00:00:00", "2025-11-27T00:00:00"]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_count_and_until() -> Result<()> {
+		let rule = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=3";
+		assert_eq!(occurrences(rule, "2024-01-01T00:00[UTC]", 10)?.len(), 3);
+		// Occurrences before the search still count
+		assert_eq!(occurrences(rule, "2024-01-02T12:00[UTC]", 10)?.len(), 1);
+
+		// A cursor picks up where the last search left off, however far into the rule
+		let rule: RRule = "DTSTART:20240101T000000Z\nRRULE:FREQ=MINUTELY;COUNT=200000".parse()?;
+		let last: Zoned = "2024-05-18T21:19[UTC]".parse()?;
+		let mut cursor = Cursor {
+			period: 199_990,
+			seen: 199_990,
+		};
+		assert_eq!(
+			rule.next_from(&last.checked_sub(1.minute())?, &mut cursor),
+			Some(last.clone())
+		);
+		assert_eq!(
+			cursor,
+			Cursor {
+				period: 199_999,
+				seen: 199_999
+			}
+		);
+		assert_eq!(rule.next_from(&last, &mut cursor), None);
+		// Searching from before the cursor starts over
+		let early: Zoned = "2024-01-01T00:05[UTC]".parse()?;
+		assert_eq!(
+			rule.next_from(&early, &mut cursor),
+			Some(early.checked_add(1.minute())?)
+		);
+		assert_eq!(cursor, Cursor { period: 6, seen: 6 });
+
+		let rule = "DTSTART:20240101T090000Z\nRRULE:FREQ=HOURLY;INTERVAL=6;UNTIL=20240102T000000Z";
+		assert_eq!(
+			occurrences(rule, "2024-01-01T00:00[UTC]", 10)?,
+			vec![
+				"2024-01-01T09:00:00",
+				"2024-01-01T15:00:00",
+				"2024-01-01T21:00:00"
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_reject_invalid() {
+		let error = |rule: &str| rule.parse::<RRule>().unwrap_err().to_string();
+		assert_eq!(
+			error("BYDAY=MO"),
+			"Invalid recurrence rule: missing FREQ".to_string()
+		);
+		assert_eq!(
+			error("FREQ=FORTNIGHTLY"),
+			"Invalid recurrence rule: unknown FREQ FORTNIGHTLY".to_string()
+		);
+		assert_eq!(
+			error("FREQ=DAILY;BYYEARDAY=100"),
+			"Invalid recurrence rule: unsupported rule part BYYEARDAY".to_string()
+		);
+		assert_eq!(
+			error("FREQ=WEEKLY;BYDAY=2MO"),
+			"Invalid recurrence rule: numbered BYDAY values need a MONTHLY or YEARLY rule"
+				.to_string()
+		);
+		assert_eq!(
+			error("FREQ=DAILY;BYHOUR=24"),
+			"Invalid recurrence rule: BYHOUR value 24 out of range".to_string()
+		);
+		assert_eq!(
+			error("DTSTART;TZID=Mars/Olympus:20240101T000000\nRRULE:FREQ=DAILY"),
+			"Invalid recurrence rule: unknown TZID Mars/Olympus".to_string()
+		);
+	}
+}
@@ -0,0 +1,328 @@
+//! The Schedule trait decides when a job runs next, and combinators compose schedules.
+
+use crate::{Calendar, Result, TimeWindow};
+use jiff::{ToSpan as _, Zoned};
+use std::fmt;
+
+/// Give up looking for a run that passes a filter after this many candidates
+const MAX_CANDIDATES: usize = 100_000;
+
+/// Anything that can compute the next run of a job.
+///
+/// [`Job`](crate::Job) implements this through its builder methods, and so do [`RRule`](crate::RRule)
+/// and [`OnCalendar`](crate::OnCalendar).  Implement it yourself for rules the builder can't
+/// express, and drive a job with `Job::with_schedule()`.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn job() {}
+/// # fn main() -> Result<()> {
+/// # let mut scheduler = Scheduler::new();
+/// use jiff::{civil::Weekday, ToSpan, Zoned};
+///
+/// /// Every third weekday at noon
+/// #[derive(Debug)]
+/// struct EveryThirdBusinessDay;
+///
+/// impl Schedule for EveryThirdBusinessDay {
+///     fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+///         let mut day = after.with().hour(12).minute(0).second(0).subsec_nanosecond(0).build().ok()?;
+///         let mut business_days = 0;
+///         while business_days < 3 {
+///             day = day.checked_add(1.day()).ok()?;
+///             if ![Weekday::Saturday, Weekday::Sunday].contains(&day.weekday()) {
+///                 business_days += 1;
+///             }
+///         }
+///         Some(day)
+///     }
+/// }
+///
+/// Job::with_schedule(EveryThirdBusinessDay).run(&mut scheduler, job)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Schedule: fmt::Debug {
+	/// Find the first run strictly after `after`, or `None` if the schedule has ended.
+	fn next_after(&self, after: &Zoned) -> Option<Zoned>;
+
+	/// Check whether the schedule covers the given moment.  Used by `and_within()` and
+	/// `except_during()` to test another schedule's runs.
+	///
+	/// By default, only the schedule's own runs are covered.  Schedules describing stretches of
+	/// time, like [`Window`] or [`Calendar`], cover every moment inside them.
+	fn contains(&self, when: &Zoned) -> bool {
+		when.checked_sub(1.nanosecond())
+			.ok()
+			.and_then(|before| self.next_after(&before))
+			.is_some_and(|next| next == *when)
+	}
+
+	/// Describe the schedule for people, like in a job's `Display` output.
+	///
+	/// Defaults to the `Debug` output.  [`RRule`](crate::RRule) and
+	/// [`OnCalendar`](crate::OnCalendar) describe themselves in their own syntax.
+	fn describe(&self) -> String {
+		format!("{self:?}")
+	}
+
+	/// Run whenever either schedule runs.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// // Weekly on Monday, or on the 1st of the month
+	/// let schedule = "Mon 09:00".parse::<OnCalendar>()?.or("*-*-01 09:00".parse::<OnCalendar>()?);
+	/// # Ok(())
+	/// # }
+	/// ```
+	fn or<S: Schedule>(self, other: S) -> Or<Self, S>
+	where
+		Self: Sized,
+	{
+		Or(self, other)
+	}
+
+	/// Only keep the runs that fall within the other schedule.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// // Every 15 minutes during business hours, on weekdays
+	/// let schedule = every(15)
+	///     .minutes()?
+	///     .and_within(Window::new("09:00", "17:00")?)
+	///     .except_during(Calendar::new().weekends());
+	/// # Ok(())
+	/// # }
+	/// ```
+	fn and_within<S: Schedule>(self, other: S) -> Within<Self, S>
+	where
+		Self: Sized,
+	{
+		Within(self, other)
+	}
+
+	/// Drop the runs that fall within the other schedule.
+	///
+	/// ```rust
+	/// # use skedge::*;
+	/// # fn main() -> Result<()> {
+	/// // Every 15 minutes, except during nightly maintenance
+	/// let schedule = every(15)
+	///     .minutes()?
+	///     .except_during(Window::new("02:00", "03:00")?);
+	/// # Ok(())
+	/// # }
+	/// ```
+	fn except_during<S: Schedule>(self, other: S) -> Except<Self, S>
+	where
+		Self: Sized,
+	{
+		Except(self, other)
+	}
+}
+
+/// Runs whenever either schedule runs.  Created by `Schedule::or()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Or<A, B>(A, B);
+
+impl<A: Schedule, B: Schedule> Schedule for Or<A, B> {
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		match (self.0.next_after(after), self.1.next_after(after)) {
+			(Some(first), Some(second)) => Some(first.min(second)),
+			(first, second) => first.or(second),
+		}
+	}
+
+	fn contains(&self, when: &Zoned) -> bool {
+		self.0.contains(when) || self.1.contains(when)
+	}
+
+	fn describe(&self) -> String {
+		format!("({} or {})", self.0.describe(), self.1.describe())
+	}
+}
+
+/// Runs of one schedule that fall within another.  Created by `Schedule::and_within()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Within<A, B>(A, B);
+
+impl<A: Schedule, B: Schedule> Schedule for Within<A, B> {
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		first_matching(&self.0, after, |run| self.1.contains(run))
+	}
+
+	fn contains(&self, when: &Zoned) -> bool {
+		self.0.contains(when) && self.1.contains(when)
+	}
+
+	fn describe(&self) -> String {
+		format!("({} within {})", self.0.describe(), self.1.describe())
+	}
+}
+
+/// Runs of one schedule that fall outside another.  Created by `Schedule::except_during()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Except<A, B>(A, B);
+
+impl<A: Schedule, B: Schedule> Schedule for Except<A, B> {
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		first_matching(&self.0, after, |run| !self.1.contains(run))
+	}
+
+	fn contains(&self, when: &Zoned) -> bool {
+		self.0.contains(when) && !self.1.contains(when)
+	}
+
+	fn describe(&self) -> String {
+		format!(
+			"({} except during {})",
+			self.0.describe(),
+			self.1.describe()
+		)
+	}
+}
+
+/// Find the first run of `schedule` after `after` that passes `keep`
+fn first_matching(
+	schedule: &impl Schedule,
+	after: &Zoned,
+	keep: impl Fn(&Zoned) -> bool,
+) -> Option<Zoned> {
+	let mut run = schedule.next_after(after)?;
+	for _ in 0..MAX_CANDIDATES {
+		if keep(&run) {
+			return Some(run);
+		}
+		run = schedule.next_after(&run)?;
+	}
+	None
+}
+
+/// A daily time-of-day window, both ends inclusive.  If `start` is later than `end`, the
+/// window spans midnight.
+///
+/// As a schedule, it runs whenever the window opens, and covers every moment inside it.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn main() -> Result<()> {
+/// let maintenance = Window::new("02:00", "03:00")?;
+/// assert!(maintenance.contains(&"2024-01-01T02:30[UTC]".parse()?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window(TimeWindow);
+
+impl Window {
+	/// Instantiate a window between two times of day, formatted `HH:MM` or `HH:MM:SS`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if either time can't be parsed, or if they're equal.
+	pub fn new(start: &str, end: &str) -> Result<Self> {
+		Ok(Self(TimeWindow::parse(start, end)?))
+	}
+}
+
+impl Schedule for Window {
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		self.0.next_opening(after).ok()
+	}
+
+	fn contains(&self, when: &Zoned) -> bool {
+		self.0.contains(when.time())
+	}
+
+	fn describe(&self) -> String {
+		self.0.to_string()
+	}
+}
+
+/// As a schedule, a calendar runs at the start of every excluded date, and covers every
+/// moment on them.
+impl Schedule for Calendar {
+	fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+		let mut date = after.date();
+		for _ in 0..MAX_CANDIDATES {
+			date = date.tomorrow().ok()?;
+			if Calendar::contains(self, date) {
+				return date.to_zoned(after.time_zone().clone()).ok();
+			}
+		}
+		None
+	}
+
+	fn contains(&self, when: &Zoned) -> bool {
+		Calendar::contains(self, when.date())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{every, OnCalendar};
+	use pretty_assertions::assert_eq;
+
+	/// Collect the first `n` runs after `from`, formatted as civil datetimes
+	fn runs(schedule: &impl Schedule, from: &str, n: usize) -> Result<Vec<String>> {
+		let mut when: Zoned = from.parse()?;
+		let mut found = Vec::new();
+		while found.len() < n {
+			let Some(next) = schedule.next_after(&when) else {
+				break;
+			};
+			found.push(next.datetime().to_string());
+			when = next;
+		}
+		Ok(found)
+	}
+
+	#[test]
+	fn test_or() -> Result<()> {
+		let schedule = "Mon 09:00"
+			.parse::<OnCalendar>()?
+			.or("*-*-01 09:00".parse::<OnCalendar>()?);
+		assert_eq!(
+			runs(&schedule, "2024-01-25T00:00[UTC]", 3)?,
+			vec![
+				"2024-01-29T09:00:00",
+				"2024-02-01T09:00:00",
+				"2024-02-05T09:00:00",
+			]
+		);
+		assert_eq!(
+			schedule.describe(),
+			"(Mon *-*-* 09:00:00 or *-*-01 09:00:00)".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_within_and_except() -> Result<()> {
+		let schedule = "*:0/30"
+			.parse::<OnCalendar>()?
+			.except_during(Window::new("02:00", "03:00")?);
+		assert_eq!(
+			runs(&schedule, "2024-01-01T01:00[UTC]", 3)?,
+			vec![
+				"2024-01-01T01:30:00",
+				"2024-01-01T03:30:00",
+				"2024-01-01T04:00:00",
+			]
+		);
+
+		// Builder jobs compose too
+		let schedule = every(6)
+			.hours()?
+			.and_within(Window::new("22:00", "06:00")?)
+			.except_during(Calendar::new().weekends());
+		assert_eq!(
+			runs(&schedule, "2024-01-05T20:00[UTC]", 2)?,
+			vec!["2024-01-08T02:00:00", "2024-01-09T02:00:00"]
+		);
+		Ok(())
+	}
+}
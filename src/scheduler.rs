@@ -1,9 +1,60 @@
 //! The scheduler is responsible for managing all scheduled jobs.
 
-use crate::{Clock, Job, Result, Tag, Timekeeper};
+use crate::{Clock, Error, Job, Result, Selector, Timekeeper};
 use jiff::{SpanRound, Unit, Zoned};
+#[cfg(feature = "random")]
+use std::sync::LazyLock;
 use tracing::debug;
 
+/// Seed for randomized intervals in dry runs, so simulations are reproducible
+const SIMULATION_SEED: u64 = 0x5EED;
+
+/// Seed for named jobs on schedulers without one, picked once so every process differs
+#[cfg(feature = "random")]
+static PROCESS_SEED: LazyLock<u64> = LazyLock::new(rand::random);
+
+/// A run that would happen during a dry run.  See `Scheduler::simulate()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedRun {
+	/// When the job would run
+	pub at: Zoned,
+	/// Position of the job in the scheduler, in the order jobs were added
+	pub job_index: usize,
+	/// Description of the job
+	pub job: String,
+}
+
+/// Controls how a job run with `Scheduler::trigger()` affects its regular schedule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriggerMode {
+	/// Run the job without touching its next run or run limit.  Any delay an adaptive job
+	/// asks for is ignored, since its next run stays put.
+	#[default]
+	KeepSchedule,
+	/// Count the run like a scheduled one, and compute the next run from now, using the delay
+	/// an adaptive job asks for.
+	Reschedule,
+}
+
+/// Controls what happens to runs missed while a job was paused.  See `Scheduler::resume()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResumePolicy {
+	/// Drop the missed runs, and continue with the first scheduled run after resuming.
+	#[default]
+	Skip,
+	/// Run once as soon as possible if any run was missed, then continue as usual.
+	RunOnce,
+}
+
+/// The outcome of a job run with `Scheduler::trigger()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriggeredRun {
+	/// Description of the job
+	pub job: String,
+	/// When the job runs next, or `None` if it reached its deadline or run limit and was removed
+	pub next_run: Option<Zoned>,
+}
+
 /// A Scheduler creates jobs, tracks recorded jobs, and executes jobs.
 #[derive(Debug, Default)]
 pub struct Scheduler {
@@ -11,6 +62,9 @@ pub struct Scheduler {
 	jobs: Vec<Job>,
 	/// Interface to current time
 	clock: Clock,
+	/// Seed for the random number generators of jobs added to this scheduler
+	#[cfg(feature = "random")]
+	rng_seed: Option<u64>,
 }
 
 impl Scheduler {
@@ -20,6 +74,41 @@ impl Scheduler {
 		Self::default()
 	}
 
+	/// Instantiate a Scheduler whose jobs draw random intervals reproducibly.
+	///
+	/// Each job gets its own generator, seeded from `seed` and the job's name, or its position
+	/// in the scheduler if it has no name.
+	///
+	/// ```rust
+	/// # use skedge::{every, Scheduler};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let mut scheduler = Scheduler::with_rng_seed(42);
+	/// every(5).to(10)?.minutes()?.run(&mut scheduler, job)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "random")]
+	#[must_use]
+	pub fn with_rng_seed(seed: u64) -> Self {
+		Self {
+			rng_seed: Some(seed),
+			..Self::default()
+		}
+	}
+
+	/// Seed for a job about to be added, if randomness should be reproducible
+	#[cfg(feature = "random")]
+	pub(crate) fn rng_seed_for(&self, name: Option<&str>) -> Option<u64> {
+		let name_hash = name.map(crate::job::stable_hash);
+		match (self.rng_seed, name_hash) {
+			(Some(seed), Some(hash)) => Some(seed ^ hash),
+			(Some(seed), None) => Some(seed.wrapping_add(self.jobs.len() as u64)),
+			(None, Some(hash)) => Some(*PROCESS_SEED ^ hash),
+			(None, None) => None,
+		}
+	}
+
 	/// Instantiate with mocked time
 	#[cfg(test)]
 	fn with_mock_time(clock: crate::time::mock::Mock) -> Self {
@@ -73,20 +162,268 @@ impl Scheduler {
 		Ok(())
 	}
 
-	/// Run all jobs, regardless of schedule.
-	pub fn run_all(&mut self, delay_seconds: u64) {
-		let num_jobs = self.jobs.len();
-		debug!("Running all {num_jobs} jobs with {delay_seconds}s delay");
+	/// Dry-run every job from `from` to `to`, inclusive, reporting each run that would happen.
+	///
+	/// Each job starts over as if it were added at `from`, and a virtual clock jumps from one
+	/// run to the next, following the same rules as `run_pending()`.  Nothing is called, and
+	/// the scheduler's jobs are left untouched.  Randomized intervals use a fixed seed, so the
+	/// same schedule always simulates the same way.
+	///
+	/// ```rust
+	/// # use skedge::{every, Scheduler};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// use jiff::Zoned;
+	/// let mut scheduler = Scheduler::new();
+	/// every(6).hours()?.run(&mut scheduler, job)?;
+	/// // 2024-03-10 is a daylight saving time change in New York
+	/// let from: Zoned = "2024-03-09T21:00[America/New_York]".parse()?;
+	/// let to: Zoned = "2024-03-10T12:00[America/New_York]".parse()?;
+	/// let runs = scheduler.simulate(&from, &to)?;
+	/// let times: Vec<String> = runs.iter().map(|run| run.at.time().to_string()).collect();
+	/// assert_eq!(times, ["04:00:00", "10:00:00"]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if any job fails to schedule.
+	pub fn simulate(&self, from: &Zoned, to: &Zoned) -> Result<Vec<SimulatedRun>> {
+		let mut probes = Vec::with_capacity(self.jobs.len());
+		for (job_index, job) in self.jobs.iter().enumerate() {
+			if job.is_paused() {
+				continue;
+			}
+			let probe = job.simulation(from, SIMULATION_SEED.wrapping_add(job_index as u64))?;
+			probes.push((job_index, job.to_string(), probe));
+		}
+
+		let mut runs = Vec::new();
+		while let Some(now) = probes
+			.iter()
+			.filter_map(|(_, _, probe)| probe.next_run.clone())
+			.min()
+		{
+			if now > *to {
+				break;
+			}
+			// Everything due at this moment runs, like a single `run_pending()` call
+			let mut cancelled = Vec::new();
+			for (idx, (job_index, job, probe)) in probes.iter_mut().enumerate() {
+				if !probe.should_run(&now) {
+					continue;
+				}
+				// One-shot jobs can be due before the simulation starts
+				if !probe.is_overdue(&now) && now >= *from {
+					runs.push(SimulatedRun {
+						at: now.clone(),
+						job_index: *job_index,
+						job: job.clone(),
+					});
+				}
+				if !probe.simulate_run(&now)? {
+					cancelled.push(idx);
+				}
+			}
+			for &idx in cancelled.iter().rev() {
+				probes.remove(idx);
+			}
+		}
+		Ok(runs)
+	}
+
+	/// Run all jobs the selector matches, regardless of schedule.  Pass `None` to run every job.
+	/// Jobs that cancel afterwards are removed, just like in `run_pending()`.
+	pub fn run_all(&mut self, selector: impl Into<Selector>, delay_seconds: u64) {
+		let selector = selector.into();
+		debug!("Running all jobs matching {selector} with {delay_seconds}s delay");
+		let mut to_remove = Vec::new();
 		let now = self.now();
-		for job in &mut self.jobs {
-			if let Err(e) = job.execute(&now) {
-				eprintln!("Error: {e}");
+		for (idx, job) in self.jobs.iter_mut().enumerate() {
+			if !selector.matches(job) || job.is_paused() {
+				continue;
+			}
+			match job.execute(&now) {
+				Ok(true) => {},
+				Ok(false) => {
+					debug!("Cancelling job {job}");
+					to_remove.push(idx);
+				},
+				Err(e) => eprintln!("Error: {e}"),
 			}
 			std::thread::sleep(std::time::Duration::from_secs(delay_seconds));
 		}
+		for &idx in to_remove.iter().rev() {
+			self.jobs.remove(idx);
+		}
+	}
+
+	/// Run every job the selector matches right away, like from an admin action.
+	///
+	/// Paused jobs run too, since triggering is explicit, and stay paused afterwards.
+	///
+	/// ```rust
+	/// # use skedge::{every, Scheduler, TriggerMode};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let mut scheduler = Scheduler::new();
+	/// every(1).hours()?.named("report").run(&mut scheduler, job)?;
+	/// let next_run = scheduler.next_run();
+	/// let runs = scheduler.trigger("report", TriggerMode::KeepSchedule)?;
+	/// assert_eq!(runs[0].next_run, next_run);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the selector matches no jobs, or if any job fails to execute.
+	pub fn trigger(
+		&mut self,
+		selector: impl Into<Selector>,
+		mode: TriggerMode,
+	) -> Result<Vec<TriggeredRun>> {
+		let selector = selector.into();
+		let now = self.now();
+		let mut runs = Vec::new();
+		let mut to_remove = Vec::new();
+		for (idx, job) in self.jobs.iter_mut().enumerate() {
+			if !selector.matches(job) {
+				continue;
+			}
+			let keep_going = match mode {
+				TriggerMode::KeepSchedule => job.call_now(&now)?,
+				TriggerMode::Reschedule => job.execute(&now)?,
+			};
+			if !keep_going {
+				debug!("Cancelling job {job}");
+				to_remove.push(idx);
+			}
+			runs.push(TriggeredRun {
+				job: job.to_string(),
+				next_run: job.next_run.clone().filter(|_| keep_going),
+			});
+		}
+		if runs.is_empty() {
+			return Err(Error::NoMatchingJobs(selector.to_string()));
+		}
+		for &idx in to_remove.iter().rev() {
+			self.jobs.remove(idx);
+		}
+		Ok(runs)
+	}
+
+	/// Pause every job the selector matches, like during maintenance.  Paused jobs stay in
+	/// the scheduler, but never run and don't count towards `next_run()`, `idle_seconds()`,
+	/// `upcoming()`, `simulate()`, or `run_all()`.  Returns how many jobs weren't already
+	/// paused.
+	///
+	/// ```rust
+	/// # use skedge::{every, ResumePolicy, Scheduler};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.named("sync").run(&mut scheduler, job)?;
+	/// scheduler.pause("sync")?;
+	/// assert_eq!(scheduler.next_run(), None);
+	/// scheduler.resume("sync", ResumePolicy::Skip)?;
+	/// assert!(scheduler.next_run().is_some());
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the selector matches no jobs.
+	pub fn pause(&mut self, selector: impl Into<Selector>) -> Result<usize> {
+		let jobs = self.targeted(&selector.into())?;
+		let mut count = 0;
+		for job in jobs.into_iter().filter(|job| !job.is_paused()) {
+			job.pause();
+			count += 1;
+		}
+		Ok(count)
+	}
+
+	/// Resume every paused job the selector matches, handling runs missed while paused
+	/// according to `policy`.  Returns how many jobs were resumed.
+	///
+	/// Skipping a missed run cancels jobs that have no later run, like one-shot jobs.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the selector matches no jobs, or if a job fails to reschedule.
+	pub fn resume(&mut self, selector: impl Into<Selector>, policy: ResumePolicy) -> Result<usize> {
+		let selector = selector.into();
+		self.targeted(&selector)?;
+		let now = self.now();
+		let mut count = 0;
+		let mut to_remove = Vec::new();
+		for (idx, job) in self.jobs.iter_mut().enumerate() {
+			if !selector.matches(job) || !job.is_paused() {
+				continue;
+			}
+			count += 1;
+			if !job.resume(&now, policy)? {
+				debug!("Cancelling job {job}");
+				to_remove.push(idx);
+			}
+		}
+		for &idx in to_remove.iter().rev() {
+			self.jobs.remove(idx);
+		}
+		Ok(count)
+	}
+
+	/// Add and remove tags on every job the selector matches.  Returns how many jobs were
+	/// retagged.
+	///
+	/// ```rust
+	/// # use skedge::{every, Scheduler, Selector};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.tagged(&["beta"]).run(&mut scheduler, job)?;
+	/// scheduler.retag(Selector::tag("beta"), &["stable"], &["beta"])?;
+	/// assert_eq!(scheduler.get_jobs(Selector::tag("stable")).len(), 1);
+	/// assert_eq!(scheduler.get_jobs(Selector::tag("beta")).len(), 0);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the selector matches no jobs.
+	pub fn retag(
+		&mut self,
+		selector: impl Into<Selector>,
+		add: &[&str],
+		remove: &[&str],
+	) -> Result<usize> {
+		let jobs = self.targeted(&selector.into())?;
+		let count = jobs.len();
+		for job in jobs {
+			job.untag(remove);
+			job.tag(add);
+		}
+		Ok(count)
+	}
+
+	/// Find the jobs the selector matches, if there are any
+	fn targeted(&mut self, selector: &Selector) -> Result<Vec<&mut Job>> {
+		let jobs: Vec<&mut Job> = self
+			.jobs
+			.iter_mut()
+			.filter(|job| selector.matches(job))
+			.collect();
+		if jobs.is_empty() {
+			return Err(Error::NoMatchingJobs(selector.to_string()));
+		}
+		Ok(jobs)
 	}
 
-	/// Get all jobs, optionally with a given tag.
+	/// Get all jobs the selector matches.  Pass `None` for every job.
 	/// ```rust
 	/// # use skedge::{every, Scheduler};
 	/// # fn job() {}
@@ -100,18 +437,15 @@ impl Scheduler {
 	/// # }
 	/// ```
 	#[must_use]
-	pub fn get_jobs(&self, tag: Option<Tag>) -> Vec<&Job> {
-		if let Some(t) = tag {
-			self.jobs
-				.iter()
-				.filter(|el| el.has_tag(&t))
-				.collect::<Vec<&Job>>()
-		} else {
-			self.jobs.iter().collect::<Vec<&Job>>()
-		}
+	pub fn get_jobs(&self, selector: impl Into<Selector>) -> Vec<&Job> {
+		let selector = selector.into();
+		self.jobs
+			.iter()
+			.filter(|job| selector.matches(job))
+			.collect()
 	}
 
-	/// Clear all jobs, optionally only with given tag.
+	/// Clear all jobs the selector matches.  Pass `None` for every job.
 	/// ```rust
 	/// # use skedge::{every, Scheduler};
 	/// # fn job() {}
@@ -125,14 +459,10 @@ impl Scheduler {
 	/// # Ok(())
 	/// # }
 	/// ```
-	pub fn clear(&mut self, tag: Option<Tag>) {
-		if let Some(t) = tag {
-			debug!("Deleting all jobs tagged {t}");
-			self.jobs.retain(|el| !el.has_tag(&t));
-		} else {
-			debug!("Deleting ALL jobs!!");
-			drop(self.jobs.drain(..));
-		}
+	pub fn clear(&mut self, selector: impl Into<Selector>) {
+		let selector = selector.into();
+		debug!("Deleting {selector}");
+		self.jobs.retain(|job| !selector.matches(job));
 	}
 
 	/// Grab the next upcoming timestamp
@@ -148,18 +478,36 @@ impl Scheduler {
 	/// # Ok(())
 	/// # }
 	/// ```
-	///
-	/// # Panics
-	///
-	/// Would panic if it can't call `min()` on an array that we know has at least one element.
 	#[must_use]
 	pub fn next_run(&self) -> Option<Zoned> {
-		if self.jobs.is_empty() {
-			None
-		} else {
-			// unwrap is safe, we know there's at least one job
-			self.jobs.iter().min().unwrap().next_run.clone()
-		}
+		self.active_jobs().min()?.next_run.clone()
+	}
+
+	/// Preview the next `n` runs across all jobs, in order, without running anything.
+	///
+	/// ```rust
+	/// # use skedge::{every, Scheduler};
+	/// # fn job() {}
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let mut scheduler = Scheduler::new();
+	/// every(10).minutes()?.run(&mut scheduler, job)?;
+	/// every(15).minutes()?.run(&mut scheduler, job)?;
+	/// let runs = scheduler.upcoming(5);
+	/// assert_eq!(runs.len(), 5);
+	/// assert!(runs.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn upcoming(&self, n: usize) -> Vec<(Zoned, &Job)> {
+		let now = self.now();
+		let mut runs: Vec<(Zoned, &Job)> = self
+			.active_jobs()
+			.flat_map(|job| job.upcoming(&now).take(n).map(move |run| (run, job)))
+			.collect();
+		runs.sort_by(|a, b| a.0.cmp(&b.0));
+		runs.truncate(n);
+		runs
 	}
 
 	/// Number of whole seconds until next run.  None if no jobs scheduled
@@ -174,6 +522,10 @@ impl Scheduler {
 	/// # Ok(())
 	/// # }
 	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the span until the next run can't be computed or rounded to seconds.
 	#[must_use]
 	pub fn idle_seconds(&self) -> Option<i64> {
 		println!("now: {}", self.now());
@@ -188,6 +540,11 @@ impl Scheduler {
 		)
 	}
 
+	/// Iterate over the jobs that aren't paused
+	fn active_jobs(&self) -> impl Iterator<Item = &Job> {
+		self.jobs.iter().filter(|job| !job.is_paused())
+	}
+
 	/// Get the most recently added job, for testing
 	#[cfg(test)]
 	fn most_recent_job(&self) -> Option<&Job> {
@@ -205,7 +562,7 @@ impl Timekeeper for Scheduler {
 
 	#[cfg(test)]
 	fn add_duration(&mut self, duration: impl Into<jiff::ZonedArithmetic>) {
-		self.clock.add_duration(duration)
+		self.clock.add_duration(duration);
 	}
 }
 
@@ -213,9 +570,11 @@ impl Timekeeper for Scheduler {
 mod tests {
 	use super::*;
 	use crate::{
+		after, at_datetime,
 		error::Result,
 		every, every_single,
 		time::mock::{Mock, START},
+		Job, Schedule,
 	};
 	use jiff::{civil, ToSpan as _};
 	use pretty_assertions::assert_eq;
@@ -406,6 +765,29 @@ mod tests {
 			deadline
 		);
 
+		// Relative deadlines count from the scheduler's clock when the job is added
+		scheduler.clear(None);
+		every_single()
+			.hour()?
+			.for_duration(90.minutes())?
+			.run(&mut scheduler, job)?;
+		every_single()
+			.hour()?
+			.until_time("06:30")?
+			.run(&mut scheduler, job)?;
+		let deadlines: Vec<Zoned> = scheduler
+			.jobs
+			.iter()
+			.map(|job| job.cancel_after.clone().unwrap())
+			.collect();
+		assert_eq!(
+			deadlines,
+			vec![
+				START.checked_add(90.minutes())?,
+				START.checked_add(23.hours().minutes(30))?
+			]
+		);
+
 		// Make sure it cancels a job after next_run passes the deadline
 		// FIXME - this test fails? call count never increments
 
@@ -449,6 +831,1037 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_every_span() -> Result<()> {
+		let mut scheduler = setup();
+
+		Job::every_span_str("1h30m")?.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(90.minutes()).unwrap())
+		);
+
+		scheduler.add_duration(90.minutes());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 1);
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(180.minutes()).unwrap())
+		);
+
+		scheduler.clear(None);
+		Job::every_span(1.week().days(2))?.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(scheduler.now().checked_add(9.days()).unwrap())
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_fixed_rate_does_not_drift() -> Result<()> {
+		use crate::ScheduleMode;
+
+		let mut scheduler = setup();
+		let cycles: i64 = 100;
+
+		// Every cycle, the loop notices the job one second late
+		every(10).seconds()?.run(&mut scheduler, job)?;
+		for _ in 0..cycles {
+			scheduler.add_duration((scheduler.idle_seconds().unwrap() + 1).seconds());
+			scheduler.run_pending()?;
+		}
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add((11 * cycles + 10).seconds()).unwrap())
+		);
+
+		let mut scheduler = setup();
+		every(10)
+			.seconds()?
+			.schedule_mode(ScheduleMode::FixedRate)
+			.run(&mut scheduler, job)?;
+		for _ in 0..cycles {
+			scheduler.add_duration((scheduler.idle_seconds().unwrap() + 1).seconds());
+			scheduler.run_pending()?;
+		}
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 100);
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add((10 * cycles + 10).seconds()).unwrap())
+		);
+
+		// Missed slots are skipped, not run back to back
+		scheduler.add_duration(35.seconds());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 101);
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add((10 * cycles + 40).seconds()).unwrap())
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_aligned() -> Result<()> {
+		let mut scheduler = setup();
+		scheduler.add_duration(200.seconds());
+
+		every(15).minutes()?.aligned()?.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(15.minutes()).unwrap())
+		);
+
+		scheduler.clear(None);
+		every(6).hours()?.aligned()?.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(5.hours()).unwrap())
+		);
+		scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(11.hours()).unwrap())
+		);
+
+		// Boundaries restart at midnight
+		scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(17.hours()).unwrap())
+		);
+
+		scheduler.clear(None);
+		every_single()
+			.hour()?
+			.at("30:00")?
+			.aligned()?
+			.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(11.hours().minutes(30)).unwrap())
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_starting_at() -> Result<()> {
+		let mut scheduler = setup();
+		let start = START.checked_add(3.days())?;
+
+		// Interval jobs run right at the start time, then on their interval
+		every(10)
+			.minutes()?
+			.starting_at(start.clone())?
+			.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(start.clone()));
+		scheduler.add_duration(3.days());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(start.checked_add(10.minutes()).unwrap())
+		);
+
+		// Pinned jobs wait for their first slot on or after the start time
+		let mut scheduler = setup();
+		every_single()
+			.day()?
+			.at("06:30")?
+			.starting_at(start.clone())?
+			.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(start.checked_add(23.hours().minutes(30)).unwrap())
+		);
+
+		// Aligned jobs count their boundaries from the start time
+		let mut scheduler = setup();
+		let start = START.checked_add(1.day().seconds(7))?;
+		every(15)
+			.minutes()?
+			.aligned()?
+			.starting_at(start.clone())?
+			.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(start.clone()));
+		scheduler.add_duration(1.day().seconds(8));
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(start.checked_add(15.minutes()).unwrap())
+		);
+
+		// Start delays count from the scheduler's clock when the job is added
+		let mut scheduler = setup();
+		scheduler.add_duration(1.hour());
+		every(10)
+			.minutes()?
+			.starting_in(2.hours())
+			.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(3.hours()).unwrap())
+		);
+		assert!(matches!(
+			every(10)
+				.minutes()?
+				.until(START.checked_add(2.hours())?)?
+				.starting_in(2.hours())
+				.run(&mut scheduler, job),
+			Err(Error::InvalidStartTime)
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_times() -> Result<()> {
+		let mut scheduler = setup();
+
+		every(5).seconds()?.times(3)?.run(&mut scheduler, job)?;
+		for remaining in [2, 1] {
+			scheduler.add_duration(5.seconds());
+			scheduler.run_pending()?;
+			assert_eq!(
+				scheduler.most_recent_job().unwrap().remaining_runs(),
+				Some(remaining)
+			);
+		}
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 2);
+
+		scheduler.add_duration(5.seconds());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs.len(), 0);
+
+		// Running everything uses up the limit too
+		every(1).seconds()?.times(1)?.run(&mut scheduler, job)?;
+		scheduler.run_all(None, 0);
+		assert_eq!(scheduler.jobs.len(), 0);
+
+		// A job that already used up its limit never runs again
+		every(1).seconds()?.times(1)?.run(&mut scheduler, job)?;
+		let now = scheduler.now();
+		let spent = &mut scheduler.jobs[0];
+		assert!(!spent.execute(&now)?);
+		assert!(!spent.execute(&now)?);
+		assert_eq!(spent.call_count, 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_one_shot() -> Result<()> {
+		let mut scheduler = setup();
+		let when = START.checked_add(45.seconds())?;
+
+		at_datetime(when.clone()).run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(when.clone()));
+
+		scheduler.add_duration(44.seconds());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 0);
+
+		// Removed as soon as it runs
+		scheduler.add_duration(1.second());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs.len(), 0);
+		assert_eq!(scheduler.next_run(), None);
+
+		// Delays count from the scheduler's clock when the job is added
+		scheduler.add_duration(1.hour());
+		after(45.seconds()).run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(1.hour().seconds(90)).unwrap())
+		);
+
+		// Running everything early still runs a one-shot job exactly once
+		scheduler.clear(None);
+		after(1.second()).run(&mut scheduler, job)?;
+		scheduler.run_all(None, 0);
+		assert_eq!(scheduler.jobs.len(), 0);
+		scheduler.add_duration(1.second());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.next_run(), None);
+		after(1.second()).run(&mut scheduler, job)?;
+		let now = scheduler.now();
+		let once = &mut scheduler.jobs[0];
+		assert!(!once.execute(&now)?);
+		assert!(!once.execute(&now)?);
+		assert_eq!(once.call_count, 1);
+
+		// A start time alone doesn't make an interval job one-shot
+		assert!(matches!(
+			every(5).starting_at(when)?.run(&mut scheduler, job),
+			Err(Error::UnitUnreachable)
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_immediately() -> Result<()> {
+		let mut scheduler = setup();
+
+		every_single()
+			.day()?
+			.at("10:30")?
+			.immediately()
+			.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(START.clone()));
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 1);
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(3.hours().minutes(30)).unwrap())
+		);
+
+		// The deadline still applies to the first run
+		scheduler.clear(None);
+		every(10)
+			.minutes()?
+			.immediately()
+			.until(START.checked_sub(1.second())?)?
+			.run(&mut scheduler, job)?;
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs.len(), 0);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_multiple_at_times() -> Result<()> {
+		let mut scheduler = setup();
+		let monday = START.date();
+
+		every_single()
+			.day()?
+			.at_times(&["09:00", "13:00", "17:30"])?
+			.run(&mut scheduler, job)?;
+		for (date, hour, minute) in [
+			(monday, 9, 0),
+			(monday, 13, 0),
+			(monday, 17, 30),
+			(monday.tomorrow()?, 9, 0),
+			(monday.tomorrow()?, 13, 0),
+		] {
+			let expected = date.at(hour, minute, 0, 0).in_tz("America/New_York")?;
+			assert_eq!(scheduler.next_run(), Some(expected.clone()));
+			scheduler.add_duration(scheduler.now().until(&expected)?);
+			scheduler.run_pending()?;
+		}
+
+		let mut scheduler = setup();
+		every_single()
+			.wednesday()?
+			.at_times(&["09:00", "17:00"])?
+			.run(&mut scheduler, job)?;
+		for day in [3, 3, 10] {
+			let expected = scheduler.next_run().unwrap();
+			assert_eq!(expected.day(), day);
+			scheduler.add_duration(scheduler.now().until(&expected)?);
+			scheduler.run_pending()?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_between() -> Result<()> {
+		let mut scheduler = setup();
+
+		// Before the window opens
+		every(10)
+			.minutes()?
+			.between("09:00", "17:00")?
+			.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(2.hours()).unwrap())
+		);
+
+		// Inside the window, then rolling over to tomorrow
+		scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(2.hours().minutes(10)).unwrap())
+		);
+		scheduler.add_duration(7.hours().minutes(50));
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(10.hours()).unwrap())
+		);
+		scheduler.add_duration(10.minutes());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(1.day().hours(2)).unwrap())
+		);
+
+		// A window spanning midnight
+		let mut scheduler = setup();
+		every(2)
+			.hours()?
+			.between("22:00", "06:00")?
+			.run(&mut scheduler, job)?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(15.hours()).unwrap())
+		);
+		for _ in 0..4 {
+			scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+			scheduler.run_pending()?;
+		}
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(23.hours()).unwrap())
+		);
+		scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(1.day().hours(15)).unwrap())
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_except_calendar() -> Result<()> {
+		use crate::{Calendar, HolidayPolicy};
+
+		let mut scheduler = setup();
+		let holidays = Calendar::new().weekends().date(civil::date(2024, 1, 2));
+
+		// Skipped runs continue on the next allowed date
+		every_single()
+			.day()?
+			.at("09:00")?
+			.except(holidays.clone())
+			.run(&mut scheduler, job)?;
+		for day in [1, 3, 4, 5, 8] {
+			let expected = civil::date(2024, 1, day)
+				.at(9, 0, 0, 0)
+				.in_tz("America/New_York")?;
+			assert_eq!(scheduler.next_run(), Some(expected.clone()));
+			scheduler.add_duration(scheduler.now().until(&expected)?);
+			scheduler.run_pending()?;
+		}
+
+		// Shifted runs move to the next business day without drifting
+		let mut scheduler = setup();
+		every_single()
+			.week()?
+			.except(holidays.date(civil::date(2024, 1, 8)))
+			.holiday_policy(HolidayPolicy::NextBusinessDay)
+			.run(&mut scheduler, job)?;
+		for offset in [1.week().days(1), 2.weeks()] {
+			let expected = START.checked_add(offset)?;
+			assert_eq!(scheduler.next_run(), Some(expected.clone()));
+			scheduler.add_duration(scheduler.now().until(&expected)?);
+			scheduler.run_pending()?;
+		}
+
+		// Jobs that only ever land on excluded dates give up instead of searching forever
+		let weekends = Calendar::new().weekends();
+		let saturday = START.checked_add(5.days())?;
+		assert!(matches!(
+			every_single()
+				.saturday()?
+				.except(weekends.clone())
+				.run(&mut scheduler, job),
+			Err(Error::CalendarExhausted)
+		));
+		assert!(matches!(
+			Job::on_calendar("Sat 09:00")?
+				.except(weekends.clone())
+				.run(&mut scheduler, job),
+			Err(Error::CalendarExhausted)
+		));
+		assert!(matches!(
+			at_datetime(saturday)
+				.except(weekends)
+				.run(&mut scheduler, job),
+			Err(Error::CalendarExhausted)
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_skip_dates_from_ics() -> Result<()> {
+		let mut scheduler = setup();
+		// Unique per process, so concurrent test runs don't share the file
+		let path = std::env::temp_dir().join(format!(
+			"skedge_test_skip_dates_from_ics_{}.ics",
+			std::process::id()
+		));
+		std::fs::write(
+			&path,
+			"BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20230102\nRRULE:FREQ=YEARLY\nEND:VEVENT\nEND:VCALENDAR\n",
+		)?;
+
+		every_single()
+			.day()?
+			.at("09:00")?
+			.skip_dates_from_ics(&path)?
+			.run(&mut scheduler, job)?;
+		std::fs::remove_file(&path)?;
+
+		scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+		scheduler.run_pending()?;
+		assert_eq!(
+			scheduler.next_run(),
+			Some(START.checked_add(2.days().hours(2)).unwrap())
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rrule() -> Result<()> {
+		let mut scheduler = setup();
+
+		Job::rrule("FREQ=DAILY;BYHOUR=9,17;BYMINUTE=0;BYSECOND=0;COUNT=3")?
+			.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(2.hours())?));
+
+		for (wait, next) in [(2.hours(), 10.hours()), (8.hours(), 26.hours())] {
+			scheduler.add_duration(wait);
+			scheduler.run_pending()?;
+			assert_eq!(scheduler.next_run(), Some(START.checked_add(next)?));
+		}
+
+		// Cancelled once the rule runs out
+		scheduler.add_duration(16.hours());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs.len(), 0);
+
+		// A rule that already ended can't be scheduled
+		assert_eq!(
+			Job::rrule("DTSTART:20230101T000000Z\nRRULE:FREQ=DAILY;UNTIL=20231231T000000Z")?
+				.run(&mut scheduler, job)
+				.unwrap_err()
+				.to_string(),
+			"Job has no upcoming runs".to_string()
+		);
+		assert_eq!(
+			Job::rrule("FREQ=DAILY")?.hours().unwrap_err().to_string(),
+			"Cannot set hours mode, already using a custom schedule".to_string()
+		);
+
+		// Previews don't pin a rule without a start, the scheduler's clock does
+		let mut scheduler = setup();
+		let rule_job = Job::rrule("FREQ=HOURLY;INTERVAL=5")?;
+		let later = START.checked_add(1.day().minutes(30))?;
+		assert_eq!(
+			rule_job.upcoming(&later).next(),
+			Some(later.checked_add(5.hours())?)
+		);
+		rule_job.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(5.hours())?));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_on_calendar() -> Result<()> {
+		let mut scheduler = setup();
+
+		// START is a Monday at 07:00
+		Job::on_calendar("Mon,Tue 08:00")?.run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(1.hour())?));
+
+		for next in [25.hours(), 169.hours()] {
+			scheduler.add_duration(scheduler.idle_seconds().unwrap().seconds());
+			scheduler.run_pending()?;
+			assert_eq!(scheduler.next_run(), Some(START.checked_add(next)?));
+		}
+		assert_eq!(scheduler.most_recent_job().unwrap().call_count, 2);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_custom_schedule() -> Result<()> {
+		/// Runs at the top of every other hour
+		#[derive(Debug)]
+		struct EvenHours;
+
+		impl Schedule for EvenHours {
+			fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+				let hour = after
+					.with()
+					.minute(0)
+					.second(0)
+					.subsec_nanosecond(0)
+					.build()
+					.ok()?;
+				let step = if hour.hour() % 2 == 0 { 2 } else { 1 };
+				hour.checked_add(step.hours()).ok()
+			}
+		}
+
+		let mut scheduler = setup();
+		Job::with_schedule(EvenHours).run(&mut scheduler, job)?;
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(1.hour())?));
+		scheduler.add_duration(1.hour());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(3.hours())?));
+
+		// Builder jobs are schedules too
+		let daily = every_single().day()?.at("10:30")?;
+		let first = daily.next_after(&START).unwrap();
+		assert_eq!(first, START.checked_add(3.hours().minutes(30))?);
+		assert_eq!(daily.next_after(&first), Some(first.checked_add(1.day())?));
+		let deadline = START.checked_add(1.hour())?;
+		assert_eq!(every(2).hours()?.until(deadline)?.next_after(&START), None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_upcoming() -> Result<()> {
+		let mut scheduler = setup();
+		assert_eq!(scheduler.upcoming(3).len(), 0);
+
+		every(20).minutes()?.run(&mut scheduler, job)?;
+		every(30).minutes()?.times(2)?.run(&mut scheduler, job)?;
+		let offsets: Vec<i64> = scheduler
+			.upcoming(5)
+			.into_iter()
+			.map(|(run, _)| START.duration_until(&run).as_mins())
+			.collect();
+		assert_eq!(offsets, vec![20, 30, 40, 60, 60]);
+
+		// Previewing doesn't change anything
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(20.minutes())?));
+		scheduler.add_duration(20.minutes());
+		scheduler.run_pending()?;
+		let offsets: Vec<i64> = scheduler
+			.upcoming(4)
+			.into_iter()
+			.map(|(run, _)| START.duration_until(&run).as_mins())
+			.collect();
+		assert_eq!(offsets, vec![30, 40, 60, 60]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_simulate() -> Result<()> {
+		let mut scheduler = setup();
+		let deadline = START.checked_add(1.day())?;
+		every_single()
+			.day()?
+			.at("09:00")?
+			.until(deadline.clone())?
+			.run(&mut scheduler, job)?;
+		every(8).hours()?.times(2)?.run(&mut scheduler, job)?;
+
+		let from = START.checked_add(1.hour())?;
+		let runs = scheduler.simulate(&from, &START.checked_add(3.days())?)?;
+		let summary: Vec<(usize, i64)> = runs
+			.iter()
+			.map(|run| (run.job_index, START.duration_until(&run.at).as_hours()))
+			.collect();
+		// The daily job's second run lands after its deadline
+		assert_eq!(summary, vec![(0, 2), (1, 9), (1, 17)]);
+
+		// Nothing actually ran
+		assert_eq!(scheduler.jobs[0].call_count, 0);
+		assert_eq!(scheduler.jobs[1].remaining_runs(), Some(2));
+
+		// Jobs start over from `from`, even after running, and relative times follow along
+		scheduler.clear(None);
+		every(8).hours()?.times(2)?.run(&mut scheduler, job)?;
+		every(30)
+			.minutes()?
+			.for_duration(1.hour())?
+			.run(&mut scheduler, job)?;
+		at_datetime(START.checked_add(30.minutes())?).run(&mut scheduler, job)?;
+		scheduler.jobs[0].next_run = Some(START.clone());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs[0].remaining_runs(), Some(1));
+		let from = START.checked_add(1.day())?;
+		let runs = scheduler.simulate(&from, &from.checked_add(1.day())?)?;
+		let summary: Vec<(usize, i64)> = runs
+			.iter()
+			.map(|run| (run.job_index, from.duration_until(&run.at).as_mins()))
+			.collect();
+		// The one-shot job was due long before `from`, so it doesn't show up
+		assert_eq!(summary, vec![(1, 30), (1, 60), (0, 480), (0, 960)]);
+
+		// Randomized intervals are reproducible
+		#[cfg(feature = "random")]
+		{
+			scheduler.clear(None);
+			every(1).to(10)?.minutes()?.run(&mut scheduler, job)?;
+			let to = START.checked_add(1.day())?;
+			assert_eq!(
+				scheduler.simulate(&START, &to)?,
+				scheduler.simulate(&START, &to)?
+			);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_rng_seed() -> Result<()> {
+		let first_runs = |mut scheduler: Scheduler, name: &str| -> Result<Vec<Zoned>> {
+			every(1).to(1000)?.seconds()?.run(&mut scheduler, job)?;
+			every(1)
+				.to(1000)?
+				.seconds()?
+				.named(name)
+				.run(&mut scheduler, job)?;
+			Ok(scheduler
+				.jobs
+				.iter()
+				.filter_map(|job| job.next_run.clone())
+				.collect())
+		};
+		let seeded = || {
+			let mut scheduler = Scheduler::with_rng_seed(7);
+			scheduler.clock = Clock::Mock(Mock::default());
+			scheduler
+		};
+
+		assert_eq!(
+			first_runs(seeded(), "backup")?,
+			first_runs(seeded(), "backup")?
+		);
+		// Without a scheduler seed, named jobs are only consistent within a process
+		assert_eq!(
+			Scheduler::with_rng_seed(7).rng_seed_for(Some("backup")),
+			Some(7 ^ crate::job::stable_hash("backup"))
+		);
+		assert_ne!(
+			Scheduler::new().rng_seed_for(Some("backup")),
+			Some(crate::job::stable_hash("backup"))
+		);
+		assert_eq!(
+			first_runs(setup(), "backup")?[1],
+			first_runs(setup(), "backup")?[1]
+		);
+		assert_ne!(
+			first_runs(setup(), "backup-host-1")?[1],
+			first_runs(setup(), "backup-host-2")?[1]
+		);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_at_random_between() -> Result<()> {
+		let mut scheduler = setup();
+		every_single()
+			.day()?
+			.at_random_between("23:00", "01:00")?
+			.rng_seed(3)
+			.run(&mut scheduler, job)?;
+
+		// Every run lands in the window, exactly once per night
+		let window = crate::TimeWindow::parse("23:00", "01:00")?;
+		let mut previous = START.clone();
+		for _ in 0..20 {
+			let next_run = scheduler.next_run().unwrap();
+			assert!(window.contains(next_run.time()));
+			let gap = previous.duration_until(&next_run).as_hours();
+			assert!((22..=26).contains(&gap) || previous == *START);
+			scheduler.add_duration(previous.until(&next_run)?);
+			scheduler.run_pending()?;
+			previous = next_run;
+		}
+		assert_eq!(scheduler.jobs[0].call_count, 20);
+
+		assert_eq!(
+			every(2)
+				.hours()?
+				.at_random_between("01:00", "04:00")
+				.unwrap_err()
+				.to_string(),
+			"Random times of day are only supported for daily and weekday jobs".to_string()
+		);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "random")]
+	fn test_jitter() -> Result<()> {
+		use crate::JitterMode;
+
+		let mut scheduler = setup();
+		every_single()
+			.day()?
+			.at("09:00")?
+			.jitter(5.minutes())?
+			.rng_seed(1)
+			.run(&mut scheduler, job)?;
+		for day in 0..10 {
+			let target = START.with().hour(9).build()?.checked_add(day.days())?;
+			let next_run = scheduler.next_run().unwrap();
+			let offset = target.duration_until(&next_run).as_secs();
+			assert!((0..=300).contains(&offset));
+			scheduler.add_duration(scheduler.now().until(&next_run)?);
+			scheduler.run_pending()?;
+		}
+
+		// Symmetric jitter moves runs both ways without drifting
+		let mut scheduler = setup();
+		every(10)
+			.minutes()?
+			.jitter_percent(10.0)?
+			.jitter_mode(JitterMode::Symmetric)
+			.rng_seed(2)
+			.run(&mut scheduler, job)?;
+		let mut offsets = Vec::new();
+		for n in 1..=50 {
+			let target = START.checked_add((10 * n).minutes())?;
+			let next_run = scheduler.next_run().unwrap();
+			offsets.push(target.duration_until(&next_run).as_secs());
+			scheduler.add_duration(scheduler.now().until(&next_run)?);
+			scheduler.run_pending()?;
+		}
+		assert!(offsets.iter().all(|offset| (-60..=60).contains(offset)));
+		assert!(offsets.iter().any(|&offset| offset < 0));
+		assert!(offsets.iter().any(|&offset| offset > 0));
+		Ok(())
+	}
+
+	#[test]
+	fn test_run_adaptive() -> Result<()> {
+		let gaps = |job: Job, delays: Vec<i64>| -> Result<Vec<i64>> {
+			let mut scheduler = setup();
+			let mut delays = delays.into_iter();
+			job.run_adaptive(&mut scheduler, move || delays.next().unwrap_or(0).minutes())?;
+			let mut gaps = Vec::new();
+			for _ in 0..5 {
+				let next_run = scheduler.next_run().unwrap();
+				gaps.push(scheduler.now().duration_until(&next_run).as_mins());
+				scheduler.add_duration(scheduler.now().until(&next_run)?);
+				scheduler.run_pending()?;
+			}
+			Ok(gaps)
+		};
+
+		// The interval only sets the first delay, and unusable delays fall back to it
+		assert_eq!(
+			gaps(every(10).minutes()?, vec![1, 20, -5, 3])?,
+			vec![10, 1, 20, 10, 3]
+		);
+		assert_eq!(
+			gaps(
+				every(10)
+					.minutes()?
+					.delay_bounds(2.minutes(), 15.minutes())?,
+				vec![1, 20, -5, 3]
+			)?,
+			vec![10, 2, 15, 2, 3]
+		);
+
+		assert!(every(10)
+			.minutes()?
+			.delay_bounds(5.minutes(), 2.minutes())
+			.is_err());
+		assert!(every(10)
+			.minutes()?
+			.delay_bounds(0.minutes(), 2.minutes())
+			.is_err());
+		assert!(every(10)
+			.minutes()?
+			.delay_bounds(1.day(), 1.month())
+			.is_err());
+
+		// Pinned jobs don't get to choose their delay
+		let mut scheduler = setup();
+		let adaptive = || 5.minutes();
+		for pinned in [
+			every_single().day()?.at("09:00")?,
+			every_single().monday()?,
+			every(15).minutes()?.aligned()?,
+			Job::on_calendar("daily")?,
+			after(5.minutes()),
+		] {
+			assert!(matches!(
+				pinned.run_adaptive(&mut scheduler, adaptive),
+				Err(Error::AdaptivePinned)
+			));
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_trigger() -> Result<()> {
+		use crate::TriggerMode;
+
+		let mut scheduler = setup();
+		every(1).hours()?.named("report").run(&mut scheduler, job)?;
+		let mut tagged = every(1).days()?.times(1)?;
+		tagged.tag(&["cleanup"]);
+		tagged.run(&mut scheduler, job)?;
+		let regular_run = scheduler.next_run();
+
+		scheduler.add_duration(10.minutes());
+		let runs = scheduler.trigger("report", TriggerMode::KeepSchedule)?;
+		assert_eq!(runs.len(), 1);
+		assert_eq!(runs[0].next_run, regular_run);
+		assert_eq!(scheduler.jobs[0].call_count, 1);
+		assert_eq!(scheduler.next_run(), regular_run);
+
+		let runs = scheduler.trigger("report", TriggerMode::Reschedule)?;
+		assert_eq!(runs[0].next_run, Some(START.checked_add(70.minutes())?));
+		assert_eq!(scheduler.jobs[0].call_count, 2);
+
+		// Counted runs use up the run limit
+		let runs = scheduler.trigger("cleanup", TriggerMode::Reschedule)?;
+		assert_eq!(runs[0].next_run, None);
+		assert_eq!(scheduler.jobs.len(), 1);
+
+		assert_eq!(
+			scheduler
+				.trigger("cleanup", TriggerMode::KeepSchedule)
+				.unwrap_err()
+				.to_string(),
+			"No jobs match \"cleanup\"".to_string()
+		);
+
+		// Only rescheduling runs use the delay an adaptive job asks for
+		let mut scheduler = setup();
+		every(1)
+			.hours()?
+			.named("poll")
+			.run_adaptive(&mut scheduler, || 5.minutes())?;
+		let runs = scheduler.trigger("poll", TriggerMode::KeepSchedule)?;
+		assert_eq!(runs[0].next_run, Some(START.checked_add(1.hour())?));
+		let runs = scheduler.trigger("poll", TriggerMode::Reschedule)?;
+		assert_eq!(runs[0].next_run, Some(START.checked_add(5.minutes())?));
+		Ok(())
+	}
+
+	#[test]
+	fn test_pause_resume() -> Result<()> {
+		use crate::ResumePolicy;
+
+		let mut scheduler = setup();
+		every(1).hours()?.named("sync").run(&mut scheduler, job)?;
+		every(2).hours()?.run(&mut scheduler, job)?;
+
+		assert_eq!(scheduler.pause("sync")?, 1);
+		assert_eq!(scheduler.pause("sync")?, 0);
+		assert_eq!(scheduler.next_run(), Some(START.checked_add(2.hours())?));
+		assert_eq!(scheduler.idle_seconds(), Some(2 * 60 * 60));
+		assert_eq!(scheduler.upcoming(3).len(), 3);
+		scheduler.add_duration(90.minutes());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.jobs[0].call_count, 0);
+
+		// Skipping the missed run continues from now
+		assert_eq!(scheduler.resume("sync", ResumePolicy::Skip)?, 1);
+		assert_eq!(
+			scheduler.jobs[0].next_run,
+			Some(START.checked_add(150.minutes())?)
+		);
+
+		// Running once catches up right away, then continues as usual
+		scheduler.pause("sync")?;
+		scheduler.add_duration(90.minutes());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.resume("sync", ResumePolicy::RunOnce)?, 1);
+		assert_eq!(scheduler.resume("sync", ResumePolicy::RunOnce)?, 0);
+		scheduler.run_pending()?;
+		let sync = scheduler
+			.get_jobs(None)
+			.into_iter()
+			.find(|job| job.name() == Some("sync"));
+		assert_eq!(sync.map(|job| job.call_count), Some(1));
+		assert_eq!(
+			sync.and_then(|job| job.next_run.clone()),
+			Some(START.checked_add(240.minutes())?)
+		);
+
+		assert!(scheduler.pause("nothing").is_err());
+
+		// Running everything leaves paused jobs alone
+		scheduler.pause("sync")?;
+		scheduler.run_all(None, 0);
+		let counts: Vec<_> = scheduler.jobs.iter().map(|job| job.call_count).collect();
+		assert_eq!(counts, vec![1, 2]);
+
+		// Skipping the only run of a one-shot job cancels it
+		let mut scheduler = setup();
+		at_datetime(START.checked_add(1.hours())?)
+			.named("once")
+			.run(&mut scheduler, job)?;
+		scheduler.pause("once")?;
+		scheduler.add_duration(2.hours());
+		scheduler.run_pending()?;
+		assert_eq!(scheduler.resume("once", ResumePolicy::Skip)?, 1);
+		assert_eq!(scheduler.jobs.len(), 0);
+		Ok(())
+	}
+
+	#[test]
+	fn test_selectors() -> Result<()> {
+		let mut scheduler = setup();
+		every(1)
+			.hours()?
+			.tagged(&["reports", "daily"])
+			.run(&mut scheduler, job)?;
+		every(1)
+			.hours()?
+			.tagged(&["reports", "beta"])
+			.run(&mut scheduler, job)?;
+		every(1).hours()?.named("sync").run(&mut scheduler, job)?;
+
+		assert_eq!(scheduler.get_jobs(None).len(), 3);
+		assert_eq!(scheduler.get_jobs(Some("reports".to_string())).len(), 2);
+		assert_eq!(
+			scheduler
+				.get_jobs(Selector::tag("reports") & !Selector::tag("beta"))
+				.len(),
+			1
+		);
+		assert_eq!(
+			scheduler
+				.get_jobs(Selector::named("sync") | Selector::tag("beta"))
+				.len(),
+			2
+		);
+		assert_eq!(scheduler.pause(Selector::all_of(&["reports", "beta"]))?, 1);
+		assert!(scheduler.jobs[1].is_paused());
+
+		assert_eq!(
+			scheduler.retag("beta", &["daily"], &["beta", "reports"])?,
+			1
+		);
+		let mut tags: Vec<&str> = scheduler.jobs[1].tags().collect();
+		tags.sort_unstable();
+		assert_eq!(tags, vec!["daily"]);
+		assert!(scheduler.retag(Selector::tag("beta"), &[], &[]).is_err());
+
+		scheduler.run_all(Selector::tag("reports"), 0);
+		let counts: Vec<_> = scheduler.jobs.iter().map(|job| job.call_count).collect();
+		assert_eq!(counts, vec![1, 0, 0]);
+
+		scheduler.clear(Selector::all_of(&[]));
+		assert_eq!(scheduler.get_jobs(None).len(), 3);
+		scheduler.clear(Selector::tag("daily") & !Selector::tag("reports"));
+		assert_eq!(scheduler.get_jobs(None).len(), 2);
+		scheduler.clear("sync");
+		assert_eq!(scheduler.get_jobs(None).len(), 1);
+		Ok(())
+	}
+
 	#[test]
 	fn test_weekday_at_time() -> Result<()> {
 		let mut scheduler = setup();
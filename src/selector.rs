@@ -0,0 +1,195 @@
+//! Selectors pick out jobs by name and tags, for queries and bulk operations on a Scheduler.
+
+use crate::{Job, Tag};
+use std::{fmt, ops};
+
+/// A query matching jobs by their names and tags.
+///
+/// Build one with [`Selector::tag()`], [`Selector::any_of()`], [`Selector::all_of()`], or
+/// [`Selector::named()`], and combine them with `&`, `|`, and `!`.  A plain string selects the
+/// jobs with that name or tag, and `None` selects every job.
+///
+/// ```rust
+/// # use skedge::*;
+/// # fn job() {}
+/// # fn main() -> Result<()> {
+/// let mut scheduler = Scheduler::new();
+/// every(1).hours()?.tagged(&["reports", "daily"]).run(&mut scheduler, job)?;
+/// every(1).hours()?.tagged(&["reports", "beta"]).run(&mut scheduler, job)?;
+/// let stable = Selector::tag("reports") & !Selector::tag("beta");
+/// assert_eq!(scheduler.get_jobs(stable).len(), 1);
+/// assert_eq!(scheduler.get_jobs(Selector::any_of(&["daily", "beta"])).len(), 2);
+/// assert_eq!(scheduler.get_jobs(None).len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector(Rule);
+
+/// The parts a selector is made of
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Rule {
+	/// Every job
+	All,
+	/// Jobs with the given name or tag
+	Target(String),
+	/// Jobs with the given name
+	Name(String),
+	/// Jobs with the given tag
+	Tag(Tag),
+	/// Jobs with at least one of the given tags
+	AnyOf(Vec<Tag>),
+	/// Jobs with every one of the given tags
+	AllOf(Vec<Tag>),
+	/// Jobs the inner rule doesn't match
+	Not(Box<Rule>),
+	/// Jobs both rules match
+	And(Box<Rule>, Box<Rule>),
+	/// Jobs either rule matches
+	Or(Box<Rule>, Box<Rule>),
+}
+
+impl Selector {
+	/// Select the jobs with the given tag
+	#[must_use]
+	pub fn tag(tag: &str) -> Self {
+		Selector(Rule::Tag(tag.to_string()))
+	}
+
+	/// Select the jobs with at least one of the given tags
+	#[must_use]
+	pub fn any_of(tags: &[&str]) -> Self {
+		Selector(Rule::AnyOf(tags.iter().map(ToString::to_string).collect()))
+	}
+
+	/// Select the jobs with every one of the given tags.  An empty list selects no jobs.
+	#[must_use]
+	pub fn all_of(tags: &[&str]) -> Self {
+		Selector(Rule::AllOf(tags.iter().map(ToString::to_string).collect()))
+	}
+
+	/// Select the jobs given the name with `Job::named()`
+	#[must_use]
+	pub fn named(name: &str) -> Self {
+		Selector(Rule::Name(name.to_string()))
+	}
+
+	/// Check whether the selector matches the given job
+	#[must_use]
+	pub fn matches(&self, job: &Job) -> bool {
+		self.0.matches(job)
+	}
+}
+
+impl Rule {
+	fn matches(&self, job: &Job) -> bool {
+		match self {
+			Rule::All => true,
+			Rule::Target(target) => job.name() == Some(target) || job.has_tag(target),
+			Rule::Name(name) => job.name() == Some(name),
+			Rule::Tag(tag) => job.has_tag(tag),
+			Rule::AnyOf(tags) => tags.iter().any(|tag| job.has_tag(tag)),
+			Rule::AllOf(tags) => !tags.is_empty() && tags.iter().all(|tag| job.has_tag(tag)),
+			Rule::Not(rule) => !rule.matches(job),
+			Rule::And(first, second) => first.matches(job) && second.matches(job),
+			Rule::Or(first, second) => first.matches(job) || second.matches(job),
+		}
+	}
+}
+
+impl From<&str> for Selector {
+	fn from(target: &str) -> Self {
+		Selector(Rule::Target(target.to_string()))
+	}
+}
+
+impl From<String> for Selector {
+	fn from(target: String) -> Self {
+		Selector(Rule::Target(target))
+	}
+}
+
+impl From<Option<Tag>> for Selector {
+	fn from(tag: Option<Tag>) -> Self {
+		Selector(tag.map_or(Rule::All, Rule::Tag))
+	}
+}
+
+impl ops::BitAnd for Selector {
+	type Output = Selector;
+
+	fn bitand(self, other: Selector) -> Selector {
+		Selector(Rule::And(Box::new(self.0), Box::new(other.0)))
+	}
+}
+
+impl ops::BitOr for Selector {
+	type Output = Selector;
+
+	fn bitor(self, other: Selector) -> Selector {
+		Selector(Rule::Or(Box::new(self.0), Box::new(other.0)))
+	}
+}
+
+impl ops::Not for Selector {
+	type Output = Selector;
+
+	fn not(self) -> Selector {
+		Selector(Rule::Not(Box::new(self.0)))
+	}
+}
+
+impl fmt::Display for Selector {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl fmt::Display for Rule {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Rule::All => write!(f, "all jobs"),
+			Rule::Target(target) => write!(f, "{target:?}"),
+			Rule::Name(name) => write!(f, "Selector::named({name:?})"),
+			Rule::Tag(tag) => write!(f, "Selector::tag({tag:?})"),
+			Rule::AnyOf(tags) => write!(f, "Selector::any_of({tags:?})"),
+			Rule::AllOf(tags) => write!(f, "Selector::all_of({tags:?})"),
+			Rule::Not(rule) => write!(f, "!{rule}"),
+			Rule::And(first, second) => write!(f, "({first} & {second})"),
+			Rule::Or(first, second) => write!(f, "({first} | {second})"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{every, Result};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_matches() -> Result<()> {
+		let job = every(1).hours()?.named("sync").tagged(&["a", "b"]);
+		assert!(Selector::from("sync").matches(&job));
+		assert!(Selector::from("a").matches(&job));
+		assert!(!Selector::from("c").matches(&job));
+		assert!(Selector::from(None).matches(&job));
+		assert!((Selector::tag("a") & !Selector::tag("c")).matches(&job));
+		assert!(!(Selector::tag("a") & !Selector::tag("b")).matches(&job));
+		assert!((Selector::tag("c") | Selector::named("sync")).matches(&job));
+		assert!(Selector::any_of(&["c", "b"]).matches(&job));
+		assert!(!Selector::all_of(&["a", "c"]).matches(&job));
+		assert!(Selector::all_of(&["a", "b"]).matches(&job));
+		assert!(!Selector::all_of(&[]).matches(&job));
+		assert!(!Selector::any_of(&[]).matches(&job));
+		Ok(())
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(
+			(Selector::tag("a") & !Selector::any_of(&["b", "c"]) | "d".into()).to_string(),
+			r#"((Selector::tag("a") & !Selector::any_of(["b", "c"])) | "d")"#.to_string()
+		);
+	}
+}
@@ -1,6 +1,7 @@
 //! For mocking purposes, access to the current time is controlled directed through this struct.
 
-use jiff::{Span, ToSpan as _, Zoned};
+use crate::{Error, Result};
+use jiff::{civil, Span, ToSpan as _, Zoned};
 use std::fmt;
 
 pub(crate) trait Timekeeper: std::fmt::Debug {
@@ -82,6 +83,104 @@ impl fmt::Display for Unit {
 	}
 }
 
+/// A time-of-day window, which may span midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TimeWindow {
+	start: civil::Time,
+	end: civil::Time,
+}
+
+impl TimeWindow {
+	/// Instantiate a window between two distinct times
+	pub(crate) fn new(start: civil::Time, end: civil::Time) -> Result<Self> {
+		if start == end {
+			return Err(Error::InvalidWindow);
+		}
+		Ok(Self { start, end })
+	}
+
+	/// Parse a window from two `HH:MM` or `HH:MM:SS` strings
+	pub(crate) fn parse(start: &str, end: &str) -> Result<Self> {
+		let parse = |time_str: &str| {
+			time_str
+				.parse::<civil::Time>()
+				.map_err(|_| Error::InvalidWindowStr(time_str.to_string()))
+		};
+		Self::new(parse(start)?, parse(end)?)
+	}
+
+	/// The first time the window opens strictly after `when`
+	pub(crate) fn next_opening(self, when: &Zoned) -> Result<Zoned> {
+		let mut opening = when.date().to_datetime(self.start);
+		if opening <= when.datetime() {
+			opening = opening.checked_add(1.day())?;
+		}
+		Ok(opening.to_zoned(when.time_zone().clone())?)
+	}
+
+	/// Pick a time of day inside the window, to the second
+	#[cfg(feature = "random")]
+	pub(crate) fn random_time(self, rng: &mut impl rand::Rng) -> civil::Time {
+		let length = self
+			.start
+			.duration_until(self.end)
+			.as_secs()
+			.rem_euclid(24 * 60 * 60);
+		self.start.wrapping_add(rng.gen_range(0..=length).seconds())
+	}
+
+	/// Place a time of day on the same occurrence of the window as `when`.  Times after
+	/// midnight in a window spanning midnight belong to the previous day's occurrence.
+	#[cfg(feature = "random")]
+	pub(crate) fn same_occurrence(
+		self,
+		when: civil::DateTime,
+		time: civil::Time,
+	) -> Result<civil::DateTime> {
+		let spans_midnight = self.start > self.end;
+		let mut date = when.date();
+		if spans_midnight && when.time() <= self.end {
+			date = date.yesterday()?;
+		}
+		if spans_midnight && time <= self.end {
+			date = date.tomorrow()?;
+		}
+		Ok(date.to_datetime(time))
+	}
+
+	/// Check whether the given time of day falls inside the window
+	pub(crate) fn contains(self, time: civil::Time) -> bool {
+		if self.start < self.end {
+			self.start <= time && time <= self.end
+		} else {
+			time >= self.start || time <= self.end
+		}
+	}
+
+	/// Move a timestamp outside the window forward to the next time the window opens
+	pub(crate) fn roll_forward(self, when: &Zoned) -> Result<Zoned> {
+		let time = when.time();
+		if self.contains(time) {
+			return Ok(when.clone());
+		}
+		// Outside a window that doesn't span midnight, we may already be past today's opening
+		let date = if self.start < self.end && time > self.end {
+			when.date().tomorrow()?
+		} else {
+			when.date()
+		};
+		Ok(date
+			.to_datetime(self.start)
+			.to_zoned(when.time_zone().clone())?)
+	}
+}
+
+impl fmt::Display for TimeWindow {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}..{}", self.start, self.end)
+	}
+}
+
 #[cfg(test)]
 pub mod mock {
 	use super::Timekeeper;